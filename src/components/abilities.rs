@@ -5,6 +5,8 @@ use amethyst::{
 
 use serde::{Deserialize, Serialize};
 
+use crate::components::DamageKind;
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub enum AbilityDirection {
     Left,
@@ -90,6 +92,19 @@ impl CooldownAbility for BarrelRollAbilityComponent {
 }
 
 impl BarrelRollAbilityComponent {
+    // blasts pass through a rolling ship, and a steel barrel also ignores collision damage
+    pub fn is_invulnerable_to(&self, damage_kind: &DamageKind) -> bool {
+        if !self.is_active() {
+            return false;
+        }
+
+        match damage_kind {
+            DamageKind::Blast => true,
+            DamageKind::Collision => self.steel_barrel,
+            _ => false,
+        }
+    }
+
     pub fn invert_direction(&mut self) {
         match self.action_direction {
             AbilityDirection::Left => {
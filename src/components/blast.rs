@@ -1,4 +1,4 @@
-use amethyst::ecs::prelude::{Component, DenseVecStorage};
+use amethyst::ecs::prelude::{Component, DenseVecStorage, Entity};
 
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +16,11 @@ pub struct BlastComponent {
     pub damage: f32,
     pub poison_damage: f32,
     pub blast_type: BlastType,
+    pub pierce: usize,             // number of enemies the blast can pass through
+    pub bounce: usize,             // number of times the blast can bounce off of arena barriers
+    pub homing: f32, // degrees per second the blast can turn towards the closest enemy
+    pub split: usize, // number of fragments the blast splits into when destroyed on impact
+    pub hit_entities: Vec<Entity>, // entities already damaged by the blast
 }
 
 impl Component for BlastComponent {
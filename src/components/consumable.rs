@@ -1,7 +1,7 @@
 use amethyst::ecs::prelude::{Component, DenseVecStorage};
 use serde::{Deserialize, Serialize};
 
-use crate::entities::SpawnableType;
+use crate::{components::PowerUp, entities::SpawnableType};
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ConsumableComponent {
@@ -14,6 +14,10 @@ pub struct ConsumableComponent {
     pub money_value: usize,
     #[serde(default)]
     pub armor_value: usize,
+    #[serde(default)]
+    pub bomb_value: usize,
+    #[serde(default)]
+    pub power_up: Option<PowerUp>,
     pub price: usize,
     pub spawnable_type: SpawnableType,
 }
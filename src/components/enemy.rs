@@ -11,6 +11,8 @@ pub struct EnemyComponent {
     pub collision_damage: f32,
     #[serde(default = "des_allied")]
     pub allied: bool,
+    #[serde(default)]
+    pub score_value: usize, // score awarded for destroying the enemy
     pub loot_probs: SpawnProbabilities,
     pub spawnable_type: SpawnableType,
 }
@@ -6,6 +6,10 @@ pub struct HealthComponent {
     pub max_value: f32,
     pub value: f32,
     pub armor: usize,
+    #[serde(default)]
+    pub resistances: Resistances,
+    #[serde(default)]
+    pub shield: Option<Shield>,
 }
 
 impl Component for HealthComponent {
@@ -21,11 +25,145 @@ impl HealthComponent {
         }
     }
 
-    pub fn take_damage(&mut self, damage_value: f32) {
-        if self.armor == 0 {
-            self.value -= damage_value;
-        } else {
-            self.armor -= 1;
+    // fraction of the damage that is resisted, negative values are weaknesses
+    pub fn resistance(&self, damage_kind: &DamageKind, critical: bool) -> f32 {
+        match damage_kind {
+            DamageKind::Blast if critical => self.resistances.critical,
+            DamageKind::Blast | DamageKind::Bomb => self.resistances.normal,
+            DamageKind::Poison => self.resistances.poison,
+            DamageKind::Collision => self.resistances.collision,
+            _ => 0.0,
         }
     }
 }
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Resistances {
+    pub normal: f32,
+    pub critical: f32,
+    pub poison: f32,
+    pub collision: f32,
+}
+
+// regenerating layer that absorbs damage before health
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Shield {
+    pub max_value: f32,
+    pub value: f32,
+    pub regen_rate: f32,  // shield regenerated per second
+    pub regen_delay: f32, // seconds after taking damage before regenerating
+    #[serde(default)]
+    pub regen_timer: f32,
+}
+
+impl Shield {
+    pub fn new(max_value: f32, regen_rate: f32, regen_delay: f32) -> Self {
+        Shield {
+            max_value,
+            value: max_value,
+            regen_rate,
+            regen_delay,
+            regen_timer: 0.0,
+        }
+    }
+
+    // returns the damage that was not absorbed by the shield
+    pub fn absorb(&mut self, damage: f32) -> f32 {
+        self.regen_timer = self.regen_delay;
+
+        let absorbed = damage.min(self.value).max(0.0);
+        self.value -= absorbed;
+        damage - absorbed
+    }
+
+    pub fn regenerate(&mut self, dt: f32) {
+        if self.regen_timer > 0.0 {
+            self.regen_timer -= dt;
+        } else if self.value < self.max_value {
+            self.value = (self.value + self.regen_rate * dt).min(self.max_value);
+        }
+    }
+}
+
+// where a DamageEvent's damage came from
+#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum DamageKind {
+    Blast,
+    Collision,
+    Barrier,
+    Bomb,
+    Poison,
+    Breach, // enemies reaching the bottom of the arena
+}
+
+impl DamageKind {
+    // armor absorbs one whole hit of these kinds of damage
+    pub fn blocked_by_armor(&self) -> bool {
+        match self {
+            DamageKind::Blast | DamageKind::Collision => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shield_absorbs_damage_before_health() {
+        let mut shield = Shield::new(50.0, 10.0, 2.0);
+
+        assert_eq!(shield.absorb(30.0), 0.0);
+        assert_eq!(shield.value, 20.0);
+    }
+
+    #[test]
+    fn test_shield_passes_through_overflow_damage() {
+        let mut shield = Shield::new(50.0, 10.0, 2.0);
+
+        assert_eq!(shield.absorb(80.0), 30.0);
+        assert_eq!(shield.value, 0.0);
+        assert_eq!(shield.absorb(10.0), 10.0);
+    }
+
+    #[test]
+    fn test_shield_regenerates_after_delay() {
+        let mut shield = Shield::new(50.0, 10.0, 2.0);
+        shield.absorb(20.0);
+
+        shield.regenerate(1.0);
+        assert_eq!(shield.value, 30.0);
+        shield.regenerate(1.0);
+        assert_eq!(shield.value, 30.0);
+
+        shield.regenerate(1.0);
+        assert_eq!(shield.value, 40.0);
+        shield.regenerate(5.0);
+        assert_eq!(shield.value, 50.0);
+    }
+
+    #[test]
+    fn test_resistance_by_damage_kind() {
+        let health = HealthComponent {
+            max_value: 100.0,
+            value: 100.0,
+            armor: 0,
+            resistances: Resistances {
+                normal: 0.5,
+                critical: 0.25,
+                poison: -0.5,
+                collision: 0.1,
+            },
+            shield: None,
+        };
+
+        assert_eq!(health.resistance(&DamageKind::Blast, false), 0.5);
+        assert_eq!(health.resistance(&DamageKind::Blast, true), 0.25);
+        assert_eq!(health.resistance(&DamageKind::Bomb, false), 0.5);
+        assert_eq!(health.resistance(&DamageKind::Poison, false), -0.5);
+        assert_eq!(health.resistance(&DamageKind::Collision, false), 0.1);
+        assert_eq!(health.resistance(&DamageKind::Breach, false), 0.0);
+    }
+}
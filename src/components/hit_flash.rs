@@ -0,0 +1,14 @@
+use amethyst::{
+    ecs::prelude::{Component, DenseVecStorage},
+    renderer::resources::Tint,
+};
+
+#[derive(Clone, Debug)]
+pub struct HitFlashComponent {
+    pub timer: f32,
+    pub original_tint: Option<Tint>, // restored once the flash ends
+}
+
+impl Component for HitFlashComponent {
+    type Storage = DenseVecStorage<Self>;
+}
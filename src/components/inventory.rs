@@ -0,0 +1,81 @@
+use crate::entities::ItemType;
+use amethyst::ecs::prelude::{Component, DenseVecStorage};
+use serde::{Deserialize, Serialize};
+
+// items the player has picked up, in the order they were first acquired
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct InventoryComponent {
+    pub items: Vec<(ItemType, usize)>,
+    #[serde(default)]
+    pub synergies: Vec<String>, // names of active synergies, in the order they activated
+}
+
+impl Component for InventoryComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl InventoryComponent {
+    pub fn add(&mut self, item_type: &ItemType) {
+        match self.items.iter_mut().find(|(owned, _)| owned == item_type) {
+            Some((_, count)) => *count += 1,
+            None => self.items.push((item_type.clone(), 1)),
+        }
+    }
+
+    pub fn count(&self, item_type: &ItemType) -> usize {
+        self.items
+            .iter()
+            .find(|(owned, _)| owned == item_type)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    pub fn has(&self, item_type: &ItemType) -> bool {
+        self.count(item_type) > 0
+    }
+
+    pub fn has_all(&self, item_types: &[ItemType]) -> bool {
+        item_types.iter().all(|item_type| self.has(item_type))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_add_stacks_items_in_acquired_order() {
+        let mut inventory = InventoryComponent::default();
+
+        inventory.add(&ItemType::Spice);
+        inventory.add(&ItemType::SteelBarrel);
+        inventory.add(&ItemType::Spice);
+
+        assert_eq!(
+            inventory.items,
+            vec![(ItemType::Spice, 2), (ItemType::SteelBarrel, 1)]
+        );
+    }
+
+    #[test]
+    fn test_count_and_has() {
+        let mut inventory = InventoryComponent::default();
+        inventory.add(&ItemType::Spice);
+        inventory.add(&ItemType::Spice);
+
+        assert_eq!(inventory.count(&ItemType::Spice), 2);
+        assert_eq!(inventory.count(&ItemType::SteelBarrel), 0);
+        assert!(inventory.has(&ItemType::Spice));
+        assert!(!inventory.has(&ItemType::SteelBarrel));
+    }
+
+    #[test]
+    fn test_has_all() {
+        let mut inventory = InventoryComponent::default();
+        inventory.add(&ItemType::Spice);
+        inventory.add(&ItemType::SteelBarrel);
+
+        assert!(inventory.has_all(&[ItemType::Spice, ItemType::SteelBarrel]));
+        assert!(!inventory.has_all(&[ItemType::Spice, ItemType::TungstenTips]));
+        assert!(inventory.has_all(&[]));
+    }
+}
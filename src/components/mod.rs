@@ -9,11 +9,16 @@ mod despawn;
 mod enemy;
 mod fade;
 mod health;
+mod hit_flash;
 mod hitbox;
+mod inventory;
 mod item;
 mod motion2d;
+mod path;
 mod planet;
 mod player;
+mod poison;
+mod power_up;
 mod spawner;
 mod status_bar;
 mod store_icon;
@@ -35,16 +40,24 @@ pub use self::{
     despawn::DespawnAtBorderComponent,
     enemy::{EnemyComponent, EnemySpawnerTag},
     fade::{ColorChannelChange, FadeComponent},
-    health::HealthComponent,
+    health::{DamageKind, HealthComponent, Resistances, Shield},
+    hit_flash::HitFlashComponent,
     hitbox::Hitbox2DComponent,
+    inventory::InventoryComponent,
     item::ItemComponent,
     motion2d::Motion2DComponent,
+    path::{PathFollowerComponent, PathType},
     planet::PlanetComponent,
     player::PlayerComponent,
+    poison::PoisonComponent,
+    power_up::{PowerUp, PowerUpType, PowerUpsComponent},
     spawner::{choose_random_entity, SpawnProbabilities, SpawnerComponent},
     status_bar::{StatusBarComponent, StatusType},
     store_icon::StoreIconComponent,
-    tags::DefenseTag,
+    tags::{DamageNumberTag, DefenseTag, DifficultyScaledTag, EffectTag, ToastTag},
     timelimit::TimeLimitComponent,
-    weapons::{AutoFireComponent, BlasterComponent, ManualFireComponent},
+    weapons::{
+        AimType, AutoFireComponent, BlasterComponent, FireMode, ManualFireComponent,
+        SecondaryFireComponent,
+    },
 };
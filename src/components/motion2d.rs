@@ -84,6 +84,36 @@ impl Motion2DComponent {
         }
     }
 
+    // rotate velocity towards the target by at most max_turn degrees without changing speed
+    pub fn steer_towards_target(&mut self, current_position: Vector2<f32>, max_turn: f32) {
+        if let Some(target_position) = self.target_position {
+            let current_angle = self.velocity.y.atan2(self.velocity.x).to_degrees();
+            let target_angle = (target_position.y - current_position.y)
+                .atan2(target_position.x - current_position.x)
+                .to_degrees();
+
+            let smallest_angle = signed_modulo(target_angle - current_angle + 180.0, 360.0) - 180.0;
+
+            let new_angle =
+                (current_angle + smallest_angle.max(-max_turn).min(max_turn)).to_radians();
+            let speed = self.velocity.norm();
+
+            self.velocity = Vector2::new(speed * new_angle.cos(), speed * new_angle.sin());
+        }
+    }
+
+    // set velocity to move straight at the target with the given speed
+    pub fn move_towards_target(&mut self, current_position: Vector2<f32>, speed: f32) {
+        if let Some(target_position) = self.target_position {
+            let direction = target_position - current_position;
+            let distance = direction.norm();
+
+            if distance > 0.0 {
+                self.velocity = direction * (speed / distance);
+            }
+        }
+    }
+
     // move in direction that the entity is facing
     pub fn move_forward(&mut self, angle: f32) {
         if self.velocity.x < self.speed.x * (angle - std::f32::consts::FRAC_PI_2).cos() {
@@ -0,0 +1,115 @@
+use crate::constants::{BEZIER_PATH_SAMPLES, PATH_WAYPOINT_THRESHOLD};
+use amethyst::{
+    core::math::Vector2,
+    ecs::prelude::{Component, DenseVecStorage},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum PathType {
+    Polyline,
+    Bezier, // chained cubic curves: anchor, control, control, anchor, ...
+}
+
+impl Default for PathType {
+    fn default() -> Self {
+        PathType::Polyline
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PathFollowerComponent {
+    pub points: Vec<Vector2<f32>>,
+    pub current_point: usize,
+    pub speed: f32,
+    pub looping: bool,
+    pub delay: f32, // seconds to wait before starting along the path
+}
+
+impl Component for PathFollowerComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl PathFollowerComponent {
+    // creates a follower for waypoints given relative to the origin
+    pub fn new(
+        path_type: &PathType,
+        waypoints: &[Vector2<f32>],
+        origin: Vector2<f32>,
+        speed: f32,
+        looping: bool,
+        delay: f32,
+    ) -> Self {
+        let waypoints: Vec<Vector2<f32>> = waypoints.iter().map(|point| origin + point).collect();
+
+        let points = match path_type {
+            PathType::Polyline => waypoints,
+            PathType::Bezier => sample_bezier(&waypoints),
+        };
+
+        PathFollowerComponent {
+            points,
+            current_point: 0,
+            speed,
+            looping,
+            delay,
+        }
+    }
+
+    pub fn target(&self) -> Option<Vector2<f32>> {
+        self.points.get(self.current_point).cloned()
+    }
+
+    // moves on to the next point once the current one is reached, returns false when the path is finished
+    pub fn advance(&mut self, current_position: Vector2<f32>, dt: f32) -> bool {
+        // a point is reached if it is closer than the distance travelled in one frame
+        let reach_distance = PATH_WAYPOINT_THRESHOLD.max(self.speed * dt);
+
+        if let Some(target) = self.target() {
+            if (target - current_position).norm() <= reach_distance {
+                self.current_point += 1;
+
+                if self.current_point >= self.points.len() && self.looping {
+                    self.current_point = 0;
+                }
+            }
+        }
+
+        self.current_point < self.points.len()
+    }
+}
+
+// sample each cubic segment into points, leftover waypoints are treated as a polyline
+fn sample_bezier(waypoints: &[Vector2<f32>]) -> Vec<Vector2<f32>> {
+    let mut points = vec![];
+
+    let start = match waypoints.first() {
+        Some(start) => *start,
+        None => return points,
+    };
+    points.push(start);
+
+    let mut anchor = start;
+    for segment in waypoints[1..].chunks(3) {
+        if segment.len() < 3 {
+            points.extend_from_slice(segment);
+            break;
+        }
+
+        for sample in 1..=BEZIER_PATH_SAMPLES {
+            let t = sample as f32 / BEZIER_PATH_SAMPLES as f32;
+            let u = 1.0 - t;
+
+            points.push(
+                anchor * (u * u * u)
+                    + segment[0] * (3.0 * u * u * t)
+                    + segment[1] * (3.0 * u * t * t)
+                    + segment[2] * (t * t * t),
+            );
+        }
+
+        anchor = segment[2];
+    }
+
+    points
+}
@@ -2,12 +2,27 @@ use amethyst::ecs::prelude::{Component, DenseVecStorage};
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct PlayerComponent {
     pub money: usize,
     pub collision_damage: f32,
+    #[serde(default)]
+    pub bombs: usize,
+    #[serde(default)]
+    pub max_bombs: usize,
+    #[serde(default)]
+    pub bomb_damage: f32,
+    #[serde(default)]
+    pub pickup_radius: f32, // consumables and items within this distance are pulled toward the player
 }
 
 impl Component for PlayerComponent {
     type Storage = DenseVecStorage<Self>;
 }
+
+impl PlayerComponent {
+    // adds bomb charges without exceeding the bomb capacity
+    pub fn add_bombs(&mut self, amount: usize) {
+        self.bombs = (self.bombs + amount).min(self.max_bombs);
+    }
+}
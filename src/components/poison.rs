@@ -0,0 +1,25 @@
+use crate::constants::{POISON_TICKS, POISON_TICK_PERIOD};
+use amethyst::ecs::prelude::{Component, DenseVecStorage};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PoisonComponent {
+    pub tick_damage: f32,
+    pub ticks_remaining: usize,
+    pub tick_timer: f32,
+}
+
+impl Component for PoisonComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl PoisonComponent {
+    // spreads the total poison damage evenly over the poison ticks
+    pub fn new(poison_damage: f32) -> Self {
+        PoisonComponent {
+            tick_damage: poison_damage / POISON_TICKS as f32,
+            ticks_remaining: POISON_TICKS,
+            tick_timer: POISON_TICK_PERIOD,
+        }
+    }
+}
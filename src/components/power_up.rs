@@ -0,0 +1,173 @@
+use crate::constants::{
+    POWER_UP_DAMAGE_MULTIPLIER, POWER_UP_ENEMY_TIME_SCALE, POWER_UP_FIRE_RATE_MULTIPLIER,
+};
+use amethyst::ecs::prelude::{Component, DenseVecStorage};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+pub enum PowerUpType {
+    RapidFire,
+    DoubleDamage,
+    Magnet,
+    Invulnerability,
+    SlowEnemies,
+}
+
+impl PowerUpType {
+    // name shown in the power-up indicator
+    pub fn display_name(&self) -> &str {
+        match self {
+            PowerUpType::RapidFire => "rapid fire",
+            PowerUpType::DoubleDamage => "double damage",
+            PowerUpType::Magnet => "magnet",
+            PowerUpType::Invulnerability => "invulnerable",
+            PowerUpType::SlowEnemies => "slow enemies",
+        }
+    }
+}
+
+// timed buff granted by a consumable
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PowerUp {
+    pub power_up_type: PowerUpType,
+    pub duration: f32,
+}
+
+// power-ups currently active on the player and their remaining durations
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct PowerUpsComponent {
+    pub active: Vec<(PowerUpType, f32)>,
+}
+
+impl Component for PowerUpsComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl PowerUpsComponent {
+    // starts the power-up, or refreshes its remaining duration if it is already active
+    pub fn activate(&mut self, power_up: &PowerUp) {
+        match self
+            .active
+            .iter_mut()
+            .find(|(active_type, _)| *active_type == power_up.power_up_type)
+        {
+            Some((_, timer)) => *timer = timer.max(power_up.duration),
+            None => self
+                .active
+                .push((power_up.power_up_type.clone(), power_up.duration)),
+        }
+    }
+
+    // counts down the remaining durations and removes expired power-ups
+    pub fn update(&mut self, dt: f32) {
+        for (_, timer) in self.active.iter_mut() {
+            *timer -= dt;
+        }
+        self.active.retain(|(_, timer)| *timer > 0.0);
+    }
+
+    pub fn is_active(&self, power_up_type: &PowerUpType) -> bool {
+        self.active
+            .iter()
+            .any(|(active_type, _)| active_type == power_up_type)
+    }
+
+    pub fn fire_rate_multiplier(&self) -> f32 {
+        if self.is_active(&PowerUpType::RapidFire) {
+            POWER_UP_FIRE_RATE_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+
+    pub fn damage_multiplier(&self) -> f32 {
+        if self.is_active(&PowerUpType::DoubleDamage) {
+            POWER_UP_DAMAGE_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+
+    pub fn enemy_time_scale(&self) -> f32 {
+        if self.is_active(&PowerUpType::SlowEnemies) {
+            POWER_UP_ENEMY_TIME_SCALE
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_power_ups_expire() {
+        let mut power_ups = PowerUpsComponent::default();
+        power_ups.activate(&PowerUp {
+            power_up_type: PowerUpType::RapidFire,
+            duration: 2.0,
+        });
+        power_ups.activate(&PowerUp {
+            power_up_type: PowerUpType::Magnet,
+            duration: 1.0,
+        });
+
+        power_ups.update(1.5);
+        assert!(power_ups.is_active(&PowerUpType::RapidFire));
+        assert!(!power_ups.is_active(&PowerUpType::Magnet));
+
+        power_ups.update(1.0);
+        assert!(power_ups.active.is_empty());
+    }
+
+    #[test]
+    fn test_activating_active_power_up_refreshes_duration() {
+        let mut power_ups = PowerUpsComponent::default();
+        power_ups.activate(&PowerUp {
+            power_up_type: PowerUpType::DoubleDamage,
+            duration: 2.0,
+        });
+        power_ups.update(1.5);
+
+        power_ups.activate(&PowerUp {
+            power_up_type: PowerUpType::DoubleDamage,
+            duration: 2.0,
+        });
+        assert_eq!(power_ups.active, vec![(PowerUpType::DoubleDamage, 2.0)]);
+
+        // a shorter power-up doesn't cut the remaining duration
+        power_ups.activate(&PowerUp {
+            power_up_type: PowerUpType::DoubleDamage,
+            duration: 1.0,
+        });
+        assert_eq!(power_ups.active, vec![(PowerUpType::DoubleDamage, 2.0)]);
+    }
+
+    #[test]
+    fn test_power_up_multipliers() {
+        let mut power_ups = PowerUpsComponent::default();
+        assert_eq!(power_ups.fire_rate_multiplier(), 1.0);
+        assert_eq!(power_ups.damage_multiplier(), 1.0);
+        assert_eq!(power_ups.enemy_time_scale(), 1.0);
+
+        power_ups.activate(&PowerUp {
+            power_up_type: PowerUpType::RapidFire,
+            duration: 1.0,
+        });
+        power_ups.activate(&PowerUp {
+            power_up_type: PowerUpType::DoubleDamage,
+            duration: 1.0,
+        });
+        power_ups.activate(&PowerUp {
+            power_up_type: PowerUpType::SlowEnemies,
+            duration: 1.0,
+        });
+        assert_eq!(
+            power_ups.fire_rate_multiplier(),
+            POWER_UP_FIRE_RATE_MULTIPLIER
+        );
+        assert_eq!(power_ups.damage_multiplier(), POWER_UP_DAMAGE_MULTIPLIER);
+        assert_eq!(power_ups.enemy_time_scale(), POWER_UP_ENEMY_TIME_SCALE);
+    }
+}
@@ -34,6 +34,20 @@ impl SpawnerComponent {
             prob_space,
         }
     }
+    /// replace the probabilities and period, keeping the current timer, spawn tables are validated
+    /// when phases.ron is loaded
+    pub fn reconfigure(&mut self, probabilities: SpawnProbabilities, period: f32) {
+        self.prob_space = calculate_total_probabilities(&probabilities);
+        self.probabilities = probabilities;
+        self.set_period(period);
+    }
+
+    /// change the time between spawns, the timer is shortened if it exceeds the new period
+    pub fn set_period(&mut self, period: f32) {
+        self.period = period;
+        self.timer = self.timer.min(period);
+    }
+
     /// spawn random item with position, if timer has expired
     pub fn spawn_with_position(&mut self, dt: f32) -> Option<(f32, &Option<SpawnableType>)> {
         if self.timer > 0.0 {
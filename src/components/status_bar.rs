@@ -10,6 +10,7 @@ pub enum StatusType {
     Defense,
     Roll,
     Restock,
+    Shield,
 }
 
 pub struct StatusBarComponent {
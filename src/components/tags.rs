@@ -6,3 +6,31 @@ pub struct DefenseTag;
 impl Component for DefenseTag {
     type Storage = NullStorage<Self>;
 }
+
+#[derive(Default)]
+pub struct DamageNumberTag;
+
+impl Component for DamageNumberTag {
+    type Storage = NullStorage<Self>;
+}
+
+#[derive(Default)]
+pub struct DifficultyScaledTag;
+
+impl Component for DifficultyScaledTag {
+    type Storage = NullStorage<Self>;
+}
+
+#[derive(Default)]
+pub struct ToastTag;
+
+impl Component for ToastTag {
+    type Storage = NullStorage<Self>;
+}
+
+#[derive(Default)]
+pub struct EffectTag;
+
+impl Component for EffectTag {
+    type Storage = NullStorage<Self>;
+}
@@ -33,6 +33,14 @@ pub struct BlasterComponent {
     pub crit_chance: f32,
     pub size_multiplier: f32,
     pub spacing: f32, // space between blasts when multiple are fired (along x axis)
+    #[serde(default)]
+    pub pierce: usize, // number of enemies each blast can pass through
+    #[serde(default)]
+    pub bounce: usize, // number of times each blast can bounce off of arena barriers
+    #[serde(default)]
+    pub homing: f32, // degrees per second each blast can turn towards the closest enemy
+    #[serde(default)]
+    pub split: usize, // number of fragments each blast splits into when destroyed on impact
 }
 
 impl Component for BlasterComponent {
@@ -40,6 +48,15 @@ impl Component for BlasterComponent {
 }
 
 impl BlasterComponent {
+    // copy of the blaster with damage and blast size scaled by the multiplier
+    pub fn charged(&self, multiplier: f32) -> Self {
+        let mut charged_blaster = self.clone();
+        charged_blaster.damage *= multiplier;
+        charged_blaster.poison_damage *= multiplier;
+        charged_blaster.size_multiplier *= multiplier;
+        charged_blaster
+    }
+
     pub fn fire(
         &self,
         source_motion2d: &Motion2DComponent,
@@ -54,13 +71,11 @@ impl BlasterComponent {
             BLAST_Z,
         );
 
-        let mut blast_damage = self.damage;
         let mut blast_poison_damage = 0.0;
         let (blast_type, blast_sprite_number) = match self.blast_type {
             // status rolls for ally
             BlastType::Ally => {
                 if thread_rng().gen::<f32>() < self.crit_chance {
-                    blast_damage *= 2.0;
                     (BlastType::AllyCritical, CRIT_BLAST_SPRITE_INDEX)
                 } else if thread_rng().gen::<f32>() < self.poison_chance {
                     blast_poison_damage = self.poison_damage;
@@ -72,10 +87,7 @@ impl BlasterComponent {
             // status rolls for enemy
             BlastType::Enemy => (BlastType::Enemy, ENEMY_BLAST_SPRITE_INDEX),
             // blasters with set status rolls will always spawn blasts with that status
-            BlastType::AllyCritical => {
-                blast_damage *= 2.0;
-                (BlastType::AllyCritical, CRIT_BLAST_SPRITE_INDEX)
-            }
+            BlastType::AllyCritical => (BlastType::AllyCritical, CRIT_BLAST_SPRITE_INDEX),
             BlastType::AllyPoison => {
                 blast_poison_damage = self.poison_damage;
                 (BlastType::AllyPoison, POISON_BLAST_SPRITE_INDEX)
@@ -112,9 +124,14 @@ impl BlasterComponent {
         };
 
         let blast_component = BlastComponent {
-            damage: blast_damage,
+            damage: self.damage,
             poison_damage: blast_poison_damage,
             blast_type,
+            pierce: self.pierce,
+            bounce: self.bounce,
+            homing: self.homing,
+            split: self.split,
+            hit_entities: vec![],
         };
 
         let blast_spawn_x = fire_position.x
@@ -150,23 +167,339 @@ impl BlasterComponent {
     }
 }
 
+// determines the direction auto fired blasts are sent
 #[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum AimType {
+    Straight, // fire along the blaster's shot velocity
+    Target,   // fire at the closest player's current position
+    Lead,     // fire at where the closest player will be when the blast arrives
+}
+
+impl Default for AimType {
+    fn default() -> Self {
+        AimType::Straight
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct AutoFireComponent {
     pub period: f32, // time between firing blasts
     pub timer: f32,  // tracks time between firing blasts
+    #[serde(default)]
+    pub aim_type: AimType,
+    #[serde(default)]
+    pub inaccuracy: f32, // max angle (in degrees) that aimed blasts can stray from the target
 }
 
 impl Component for AutoFireComponent {
     type Storage = DenseVecStorage<Self>;
 }
 
+impl AutoFireComponent {
+    // get the shot velocity for the next blast, aiming at the target (position, velocity) if there is one
+    pub fn aimed_shot_velocity(
+        &self,
+        blaster: &BlasterComponent,
+        source_position: Vector2<f32>,
+        target: Option<(Vector2<f32>, Vector2<f32>)>,
+    ) -> Vector2<f32> {
+        let shot_speed = blaster.shot_velocity.norm();
+
+        let (target_position, target_velocity) = match (&self.aim_type, target) {
+            (AimType::Straight, _) | (_, None) => return blaster.shot_velocity,
+            (AimType::Target, Some((position, _))) => (position, Vector2::new(0.0, 0.0)),
+            (AimType::Lead, Some((position, velocity))) => (position, velocity),
+        };
+
+        if shot_speed <= 0.0 {
+            return blaster.shot_velocity;
+        }
+
+        // estimate where the target will be by the time the blast reaches its current position
+        let fire_position = source_position + blaster.offset;
+        let travel_time = (target_position - fire_position).norm() / shot_speed;
+        let aim_position = target_position + (target_velocity * travel_time);
+
+        let mut aim_angle =
+            (aim_position.y - fire_position.y).atan2(aim_position.x - fire_position.x);
+
+        if self.inaccuracy > 0.0 {
+            aim_angle += thread_rng()
+                .gen_range(-self.inaccuracy, self.inaccuracy)
+                .to_radians();
+        }
+
+        Vector2::new(shot_speed * aim_angle.cos(), shot_speed * aim_angle.sin())
+    }
+}
+
+// determines how the fire action is turned into blasts
 #[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum FireMode {
+    Rapid, // fire whenever the fire action is held and ready
+    Charge {
+        charge_period: f32,  // time the fire action must be held to reach a full charge
+        max_multiplier: f32, // damage and size multiplier of a fully charged blast
+    },
+}
+
+impl Default for FireMode {
+    fn default() -> Self {
+        FireMode::Rapid
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct ManualFireComponent {
     pub period: f32, // time between firing blasts
     pub timer: f32,  // tracks time between firing blasts
     pub ready: bool,
+    #[serde(default)]
+    pub fire_mode: FireMode,
+    #[serde(default)]
+    pub charge_timer: f32, // tracks how long the fire action has been held while charging
 }
 
 impl Component for ManualFireComponent {
     type Storage = DenseVecStorage<Self>;
 }
+
+impl ManualFireComponent {
+    // count down to being ready to fire again
+    pub fn update(&mut self, dt: f32) {
+        if self.timer > 0.0 && !self.ready {
+            self.timer -= dt;
+        } else if !self.ready {
+            self.timer = self.period;
+            self.ready = true;
+        }
+    }
+
+    // returns the multiplier of the blast to fire, if the fire action should fire one
+    pub fn trigger(&mut self, fire_action: bool, dt: f32) -> Option<f32> {
+        if !self.ready {
+            return None;
+        }
+
+        match self.fire_mode {
+            FireMode::Rapid => {
+                if fire_action {
+                    self.ready = false;
+                    return Some(1.0);
+                }
+            }
+
+            FireMode::Charge {
+                charge_period,
+                max_multiplier,
+            } => {
+                if charge_period <= 0.0 {
+                    // without a charge period every blast is fully charged
+                    if fire_action {
+                        self.ready = false;
+                        return Some(max_multiplier);
+                    }
+                } else if fire_action {
+                    self.charge_timer = (self.charge_timer + dt).min(charge_period);
+                } else if self.charge_timer > 0.0 {
+                    // blast is fired when the fire action is released
+                    let charge = self.charge_timer / charge_period;
+                    self.charge_timer = 0.0;
+                    self.ready = false;
+                    return Some(1.0 + ((max_multiplier - 1.0) * charge));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+// additional weapon fired with its own action
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SecondaryFireComponent {
+    pub blaster: BlasterComponent,
+    pub manual_fire: ManualFireComponent,
+}
+
+impl Component for SecondaryFireComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn blaster(shot_velocity: Vector2<f32>) -> BlasterComponent {
+        BlasterComponent {
+            count: 1,
+            blast_type: BlastType::Enemy,
+            shot_velocity,
+            velocity_multiplier: 0.0,
+            offset: Vector2::new(0.0, 0.0),
+            damage: 10.0,
+            poison_damage: 0.0,
+            poison_chance: 0.0,
+            crit_chance: 0.0,
+            size_multiplier: 1.0,
+            spacing: 0.0,
+            pierce: 0,
+            bounce: 0,
+            homing: 0.0,
+            split: 0,
+        }
+    }
+
+    fn assert_close(actual: Vector2<f32>, expected: Vector2<f32>) {
+        assert!(
+            (actual - expected).norm() < 0.001,
+            "expected {:?}, got {:?}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_straight_shot_ignores_target() {
+        let blaster = blaster(Vector2::new(0.0, -100.0));
+        let target = Some((Vector2::new(50.0, 0.0), Vector2::new(0.0, 0.0)));
+
+        let velocity = AutoFireComponent::default().aimed_shot_velocity(
+            &blaster,
+            Vector2::new(0.0, 100.0),
+            target,
+        );
+
+        assert_close(velocity, Vector2::new(0.0, -100.0));
+    }
+
+    #[test]
+    fn test_aimed_shot_without_target_fires_straight() {
+        let blaster = blaster(Vector2::new(0.0, -100.0));
+        let autofire = AutoFireComponent {
+            aim_type: AimType::Target,
+            ..AutoFireComponent::default()
+        };
+
+        let velocity = autofire.aimed_shot_velocity(&blaster, Vector2::new(0.0, 0.0), None);
+
+        assert_close(velocity, Vector2::new(0.0, -100.0));
+    }
+
+    #[test]
+    fn test_target_shot_aims_at_target_position() {
+        let blaster = blaster(Vector2::new(0.0, -100.0));
+        let autofire = AutoFireComponent {
+            aim_type: AimType::Target,
+            ..AutoFireComponent::default()
+        };
+        // the target's velocity is ignored when aiming at its current position
+        let target = Some((Vector2::new(100.0, 0.0), Vector2::new(0.0, 50.0)));
+
+        let velocity = autofire.aimed_shot_velocity(&blaster, Vector2::new(0.0, 0.0), target);
+
+        assert_close(velocity, Vector2::new(100.0, 0.0));
+    }
+
+    #[test]
+    fn test_lead_shot_aims_ahead_of_target() {
+        let blaster = blaster(Vector2::new(0.0, -100.0));
+        let autofire = AutoFireComponent {
+            aim_type: AimType::Lead,
+            ..AutoFireComponent::default()
+        };
+        // reached in 1 second, by which time the target has moved up by 100
+        let target = Some((Vector2::new(100.0, 0.0), Vector2::new(0.0, 100.0)));
+
+        let velocity = autofire.aimed_shot_velocity(&blaster, Vector2::new(0.0, 0.0), target);
+
+        let expected = Vector2::new(1.0, 1.0).normalize() * 100.0;
+        assert_close(velocity, expected);
+    }
+
+    #[test]
+    fn test_aimed_shot_uses_blaster_offset() {
+        let mut blaster = blaster(Vector2::new(0.0, -100.0));
+        blaster.offset = Vector2::new(0.0, 100.0);
+        let autofire = AutoFireComponent {
+            aim_type: AimType::Target,
+            ..AutoFireComponent::default()
+        };
+        let target = Some((Vector2::new(0.0, 0.0), Vector2::new(0.0, 0.0)));
+
+        let velocity = autofire.aimed_shot_velocity(&blaster, Vector2::new(0.0, 0.0), target);
+
+        assert_close(velocity, Vector2::new(0.0, -100.0));
+    }
+
+    #[test]
+    fn test_rapid_fire_fires_while_held() {
+        let mut manual_fire = ManualFireComponent {
+            ready: true,
+            ..ManualFireComponent::default()
+        };
+
+        assert_eq!(manual_fire.trigger(false, 0.1), None);
+        assert_eq!(manual_fire.trigger(true, 0.1), Some(1.0));
+        assert!(!manual_fire.ready);
+        assert_eq!(manual_fire.trigger(true, 0.1), None);
+    }
+
+    #[test]
+    fn test_charge_fires_on_release() {
+        let mut manual_fire = ManualFireComponent {
+            ready: true,
+            fire_mode: FireMode::Charge {
+                charge_period: 1.0,
+                max_multiplier: 3.0,
+            },
+            ..ManualFireComponent::default()
+        };
+
+        assert_eq!(manual_fire.trigger(true, 0.5), None);
+        assert_eq!(manual_fire.trigger(false, 0.1), Some(2.0));
+        assert!(!manual_fire.ready);
+        assert_eq!(manual_fire.charge_timer, 0.0);
+    }
+
+    #[test]
+    fn test_charge_is_capped_at_full_charge() {
+        let mut manual_fire = ManualFireComponent {
+            ready: true,
+            fire_mode: FireMode::Charge {
+                charge_period: 1.0,
+                max_multiplier: 3.0,
+            },
+            ..ManualFireComponent::default()
+        };
+
+        for _ in 0..5 {
+            assert_eq!(manual_fire.trigger(true, 0.5), None);
+        }
+
+        assert_eq!(manual_fire.trigger(false, 0.1), Some(3.0));
+    }
+
+    #[test]
+    fn test_charge_without_period_fires_fully_charged() {
+        let mut manual_fire = ManualFireComponent {
+            ready: true,
+            fire_mode: FireMode::Charge {
+                charge_period: 0.0,
+                max_multiplier: 3.0,
+            },
+            ..ManualFireComponent::default()
+        };
+
+        assert_eq!(manual_fire.trigger(true, 0.1), Some(3.0));
+        assert!(!manual_fire.ready);
+    }
+
+    #[test]
+    fn test_charged_blaster_scales_damage_and_size() {
+        let charged_blaster = blaster(Vector2::new(0.0, 100.0)).charged(2.0);
+
+        assert_eq!(charged_blaster.damage, 20.0);
+        assert_eq!(charged_blaster.size_multiplier, 2.0);
+    }
+}
@@ -26,6 +26,58 @@ pub const SIDE_PANEL_Z: f32 = 0.8;
 // stats
 pub const DEFENSE: f32 = 500.0;
 pub const ENEMY_SPAWN_INTERVAL: f32 = 1.5; // time between enemy spawns
+pub const CRIT_DAMAGE_MULTIPLIER: f32 = 2.0; // applied to critical hits by the damage system
+
+// blast modifiers
+pub const BLAST_FRAGMENT_DAMAGE_RATIO: f32 = 0.5; // damage of split fragments relative to the original blast
+pub const BLAST_FRAGMENT_SIZE_RATIO: f32 = 0.6; // size of split fragments relative to the original blast
+
+// hit feedback
+pub const DAMAGE_NUMBER_DURATION: f32 = 0.8;
+pub const DAMAGE_NUMBER_RISE_SPEED: f32 = 20.0;
+pub const DAMAGE_NUMBER_FADE_DELTA: f32 = -0.02; // alpha change of damage numbers per frame
+pub const DAMAGE_NUMBER_OFFSET_Y: f32 = 8.0;
+pub const DAMAGE_NUMBER_SPREAD_X: f32 = 4.0; // random horizontal offset so stacked numbers stay readable
+pub const HIT_FLASH_DURATION: f32 = 0.08;
+pub const HIT_FLASH_INTENSITY: f32 = 4.0; // tint multiplier that washes a sprite out to white
+
+// formation paths
+pub const PATH_WAYPOINT_THRESHOLD: f32 = 2.0; // distance at which a path point counts as reached
+pub const BEZIER_PATH_SAMPLES: usize = 12; // points sampled along each bezier segment
+
+// poison
+pub const POISON_TICKS: usize = 4;
+pub const POISON_TICK_PERIOD: f32 = 0.5;
+
+// score
+pub const COMBO_WINDOW: f32 = 2.0; // seconds after a kill for the next kill to continue the combo
+pub const COMBO_MULTIPLIER_STEP: f32 = 0.1; // score multiplier gained for each kill in a combo
+pub const COMBO_MAX_MULTIPLIER: f32 = 4.0;
+pub const FULL_DEFENSE_PHASE_BONUS: usize = 1000; // score for finishing a phase without defense damage
+
+// high scores
+pub const HIGH_SCORES_FILE: &str = "high_scores.ron"; // stored in the config directory
+pub const MAX_HIGH_SCORES: usize = 10;
+pub const HIGH_SCORE_NAME_LENGTH: usize = 8;
+
+// saves
+pub const SAVE_FILE: &str = "save.ron"; // stored in the config directory
+
+// profile
+pub const PROFILE_FILE: &str = "profile.ron"; // stored in the config directory
+pub const DEFAULT_CHARACTER: &str = "juggernaut";
+
+// run stats
+pub const RUN_STATS_DIRECTORY: &str = "run_stats"; // exports, stored in the config directory
+
+// achievements
+pub const ACHIEVEMENTS_FILE: &str = "achievements.ron"; // progress, stored in the config directory
+
+// toasts
+pub const TOAST_DURATION: f32 = 3.0;
+pub const TOAST_SPACING: f32 = 24.0; // vertical space between stacked toasts
+pub const UNLOCK_TOAST_COLOR: [f32; 4] = [0.4, 0.8, 1.0, 1.0];
+pub const HIGHLIGHT_COLOR: [f32; 4] = [1.0, 0.85, 0.2, 1.0]; // achievement toasts and highlighted text
 
 // sprites
 pub const PLAYER_BLAST_SPRITE_INDEX: usize = 0;
@@ -66,6 +118,8 @@ pub const SPACESHIP_BARREL_COOLDOWN: f32 = 2.0;
 pub const SPACESHIP_BARREL_SPEED: f32 = 180.0;
 pub const SPACESHIP_BARREL_DURATION: f32 = 0.3;
 pub const SPACESHIP_HEALTH: f32 = 400.0;
+pub const SPACESHIP_SHIELD_REGEN_RATE: f32 = 10.0; // used when an item grants a shield to a ship without one
+pub const SPACESHIP_SHIELD_REGEN_DELAY: f32 = 3.0;
 
 // status bar
 pub const HEALTH_BAR_X: f32 = 332.0;
@@ -74,9 +128,44 @@ pub const HEALTH_BAR_LIMIT: f32 = 63.0;
 pub const DEFENSE_BAR_X: f32 = 352.0;
 pub const DEFENSE_BAR_Y: f32 = 200.0;
 pub const DEFENSE_BAR_LIMIT: f32 = 63.0;
+pub const SHIELD_BAR_X: f32 = 342.0;
+pub const SHIELD_BAR_Y: f32 = 200.0;
+pub const SHIELD_BAR_LIMIT: f32 = 63.0;
 pub const ROLL_BAR_X: f32 = 324.0;
 pub const ROLL_BAR_Y: f32 = 177.0;
 pub const ROLL_BAR_LIMIT: f32 = 28.0;
 pub const RESTOCK_BAR_X: f32 = 324.0;
 pub const RESTOCK_BAR_Y: f32 = 90.0;
 pub const RESTOCK_BAR_LIMIT: f32 = 28.0;
+
+// inventory display in the left side panel
+pub const INVENTORY_ICON_X: f32 = 12.0;
+pub const INVENTORY_ICON_Y: f32 = 20.0;
+pub const INVENTORY_ICON_SPACING: f32 = 19.0;
+pub const INVENTORY_ICONS_PER_ROW: usize = 2;
+pub const INVENTORY_ICON_Z: f32 = 0.9;
+pub const SYNERGY_LABEL_Y: f32 = -84.0; // below the score, from the top of the screen
+pub const SYNERGY_LABEL_SPACING: f32 = 18.0;
+pub const SYNERGY_COLOR: [f32; 4] = [0.45, 0.85, 1.0, 1.0]; // synergy labels and toasts
+
+// store slots in the right side panel, filled top to bottom one column at a time
+pub const STORE_SLOT_X: f32 = 12.0; // from the right edge of the arena
+pub const STORE_SLOT_Y: f32 = 72.0; // top slot, from the bottom of the arena
+pub const STORE_SLOT_SPACING: f32 = 19.0;
+pub const STORE_SLOTS_PER_COLUMN: usize = 3;
+pub const STORE_MAX_SLOTS: usize = 6; // slots with a bound buy action, fills two columns
+pub const STORE_SLOT_Z: f32 = 0.9;
+pub const STORE_PRICE_X: f32 = -6.0; // ui position of the price of the top slot in the last column
+pub const STORE_PRICE_Y: f32 = 130.0;
+pub const STORE_PRICE_SPACING_X: f32 = 34.0;
+pub const STORE_PRICE_SPACING_Y: f32 = 40.0;
+pub const STORE_LOCKED_PRICE_COLOR: [f32; 4] = [0.45, 0.85, 1.0, 1.0];
+
+// power-ups
+pub const POWER_UP_FIRE_RATE_MULTIPLIER: f32 = 2.0;
+pub const POWER_UP_DAMAGE_MULTIPLIER: f32 = 2.0;
+pub const POWER_UP_ENEMY_TIME_SCALE: f32 = 0.5; // enemies move at this fraction of their speed
+pub const POWER_UP_MAGNET_RADIUS: f32 = 80.0; // pickup radius while the magnet power-up is active
+
+// pickups
+pub const PICKUP_MAGNET_SPEED: f32 = 120.0; // speed pickups are pulled toward the player
@@ -1,6 +1,7 @@
 use amethyst::utils::application_root_dir;
 use std::fs::{DirBuilder, File};
 use std::io::prelude::*;
+use std::path::PathBuf;
 
 macro_rules! confgen {
     ( $($filename:expr),* ) => {
@@ -37,3 +38,11 @@ pub fn generate_configs() {
         "spritesheets_config.ron"
     );
 }
+
+// path of a file kept in the config directory, such as saves and high scores
+pub fn config_file_path(file_name: &str) -> PathBuf {
+    application_root_dir()
+        .unwrap()
+        .join("config")
+        .join(file_name)
+}
@@ -8,6 +8,9 @@ pub struct IncludeData<'a> {
     pub store: &'a [u8],
     pub game_parameters: &'a [u8],
     pub formations: &'a [u8],
+    pub unlocks: &'a [u8],
+    pub achievements: &'a [u8],
+    pub synergies: &'a [u8],
 }
 
 pub fn load_include_data() -> IncludeData<'static> {
@@ -21,5 +24,8 @@ pub fn load_include_data() -> IncludeData<'static> {
         store: include_bytes!("store.ron"),
         game_parameters: include_bytes!("game_parameters.ron"),
         formations: include_bytes!("formations.ron"),
+        unlocks: include_bytes!("unlocks.ron"),
+        achievements: include_bytes!("achievements.ron"),
+        synergies: include_bytes!("synergies.ron"),
     }
 }
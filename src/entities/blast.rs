@@ -1,11 +1,12 @@
 use amethyst::{
-    core::transform::Transform,
+    core::{math::Vector3, transform::Transform},
     ecs::prelude::{Builder, Entities, LazyUpdate, ReadExpect},
     renderer::{SpriteRender, Transparent},
 };
 
-use crate::components::{
-    BlastComponent, DespawnAtBorderComponent, Hitbox2DComponent, Motion2DComponent,
+use crate::{
+    components::{BlastComponent, DespawnAtBorderComponent, Hitbox2DComponent, Motion2DComponent},
+    constants::{BLAST_FRAGMENT_DAMAGE_RATIO, BLAST_FRAGMENT_SIZE_RATIO},
 };
 
 // spaces and creates blast entities
@@ -21,22 +22,103 @@ pub fn spawn_blasts(
     lazy_update: &ReadExpect<LazyUpdate>,
 ) {
     for _ in 0..blast_count {
-        lazy_update
-            .create_entity(entities)
-            .with(blast_component.clone())
-            .with(blast_hitbox.clone())
-            .with(blast_motion2d.clone())
-            .with(blast_sprite_render.clone())
-            .with(blast_transform.clone())
-            .with(Transparent)
-            .with(DespawnAtBorderComponent {
-                top_offset: Some(2.0),
-                bottom_offset: Some(-2.0),
-                left_offset: Some(-2.0),
-                right_offset: Some(2.0),
-            })
-            .build();
+        spawn_blast(
+            blast_sprite_render.clone(),
+            blast_component.clone(),
+            blast_hitbox.clone(),
+            blast_motion2d.clone(),
+            blast_transform.clone(),
+            entities,
+            lazy_update,
+        );
 
         blast_transform.prepend_translation_x(blast_spacing);
     }
 }
+
+// splits a blast into smaller fragments spread evenly around its direction of travel
+pub fn spawn_blast_fragments(
+    blast_component: &BlastComponent,
+    blast_sprite_render: SpriteRender,
+    blast_hitbox: Hitbox2DComponent,
+    blast_motion2d: Motion2DComponent,
+    blast_transform: Transform,
+    entities: &Entities,
+    lazy_update: &ReadExpect<LazyUpdate>,
+) {
+    // fragments keep the blast's bounce and homing, but don't pierce or split again
+    let fragment_component = BlastComponent {
+        damage: blast_component.damage * BLAST_FRAGMENT_DAMAGE_RATIO,
+        poison_damage: blast_component.poison_damage * BLAST_FRAGMENT_DAMAGE_RATIO,
+        blast_type: blast_component.blast_type.clone(),
+        pierce: 0,
+        bounce: blast_component.bounce,
+        homing: blast_component.homing,
+        split: 0,
+        hit_entities: blast_component.hit_entities.clone(),
+    };
+
+    let fragment_hitbox = Hitbox2DComponent {
+        width: blast_hitbox.width * BLAST_FRAGMENT_SIZE_RATIO,
+        height: blast_hitbox.height * BLAST_FRAGMENT_SIZE_RATIO,
+        ..blast_hitbox
+    };
+
+    let mut fragment_transform = blast_transform;
+    let blast_scale = *fragment_transform.scale();
+    fragment_transform.set_scale(Vector3::new(
+        blast_scale.x * BLAST_FRAGMENT_SIZE_RATIO,
+        blast_scale.y * BLAST_FRAGMENT_SIZE_RATIO,
+        blast_scale.z,
+    ));
+
+    let speed = blast_motion2d.velocity.norm();
+    let heading = blast_motion2d.velocity.y.atan2(blast_motion2d.velocity.x);
+    let spread = std::f32::consts::PI * 2.0 / blast_component.split as f32;
+
+    for i in 0..blast_component.split {
+        // offset by half of the spread so that no fragment flies straight ahead
+        let fragment_angle = heading + (spread / 2.0) + (spread * i as f32);
+
+        let mut fragment_motion2d = blast_motion2d.clone();
+        fragment_motion2d.velocity.x = speed * fragment_angle.cos();
+        fragment_motion2d.velocity.y = speed * fragment_angle.sin();
+        fragment_motion2d.target_position = None;
+
+        spawn_blast(
+            blast_sprite_render.clone(),
+            fragment_component.clone(),
+            fragment_hitbox.clone(),
+            fragment_motion2d,
+            fragment_transform.clone(),
+            entities,
+            lazy_update,
+        );
+    }
+}
+
+fn spawn_blast(
+    blast_sprite_render: SpriteRender,
+    blast_component: BlastComponent,
+    blast_hitbox: Hitbox2DComponent,
+    blast_motion2d: Motion2DComponent,
+    blast_transform: Transform,
+    entities: &Entities,
+    lazy_update: &ReadExpect<LazyUpdate>,
+) {
+    lazy_update
+        .create_entity(entities)
+        .with(blast_component)
+        .with(blast_hitbox)
+        .with(blast_motion2d)
+        .with(blast_sprite_render)
+        .with(blast_transform)
+        .with(Transparent)
+        .with(DespawnAtBorderComponent {
+            top_offset: Some(2.0),
+            bottom_offset: Some(-2.0),
+            left_offset: Some(-2.0),
+            right_offset: Some(2.0),
+        })
+        .build();
+}
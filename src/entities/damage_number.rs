@@ -0,0 +1,111 @@
+use crate::{
+    components::{
+        ColorChannelChange, DamageKind, DamageNumberTag, FadeComponent, Motion2DComponent,
+        TimeLimitComponent,
+    },
+    constants::{DAMAGE_NUMBER_DURATION, DAMAGE_NUMBER_FADE_DELTA, DAMAGE_NUMBER_RISE_SPEED},
+    events::EntityDamagedEvent,
+};
+use amethyst::{
+    core::{
+        math::{Vector2, Vector3},
+        transform::Transform,
+    },
+    ecs::prelude::{Builder, Entities, LazyUpdate, ReadExpect},
+    renderer::{palette::Srgba, resources::Tint},
+    ui::{Anchor, FontHandle, LineMode, UiText, UiTransform},
+};
+
+pub fn spawn_damage_number(
+    damage_event: &EntityDamagedEvent,
+    spawn_position: Vector3<f32>,
+    font: FontHandle,
+    entities: &Entities,
+    lazy_update: &ReadExpect<LazyUpdate>,
+) {
+    // critical hits and poison ticks are styled differently from regular damage
+    let (text, color, font_size) = if damage_event.critical {
+        (
+            format!("{}!", damage_event.amount.round()),
+            [1.0, 0.85, 0.2],
+            18.0,
+        )
+    } else if let DamageKind::Poison = damage_event.kind {
+        (
+            format!("{}", damage_event.amount.round()),
+            [0.45, 1.0, 0.3],
+            12.0,
+        )
+    } else {
+        (
+            format!("{}", damage_event.amount.round()),
+            [1.0, 1.0, 1.0],
+            14.0,
+        )
+    };
+
+    let mut local_transform = Transform::default();
+    local_transform.set_translation(spawn_position);
+
+    let motion_2d = Motion2DComponent {
+        velocity: Vector2::new(0.0, DAMAGE_NUMBER_RISE_SPEED),
+        acceleration: Vector2::new(0.0, 0.0),
+        deceleration: Vector2::new(0.0, 0.0),
+        angular_velocity: 0.0,
+        angular_acceleration: 0.0,
+        angular_deceleration: 0.0,
+        angular_speed: 0.0,
+        speed: Vector2::new(0.0, DAMAGE_NUMBER_RISE_SPEED),
+        max_speed: Vector2::new(0.0, DAMAGE_NUMBER_RISE_SPEED),
+        immovable: false,
+        target_position: None,
+    };
+
+    let fade = FadeComponent {
+        red_change: None,
+        green_change: None,
+        blue_change: None,
+        alpha_change: Some(ColorChannelChange {
+            delta_value: DAMAGE_NUMBER_FADE_DELTA,
+            value: 1.0,
+            min_value: 0.0,
+            max_value: 1.0,
+        }),
+    };
+
+    // the ui transform is positioned over the world transform by the DamageNumberSystem
+    let ui_transform = UiTransform::new(
+        "damage_number".to_string(),
+        Anchor::BottomLeft,
+        Anchor::Middle,
+        0.0,
+        0.0,
+        0.9,
+        60.0,
+        30.0,
+    );
+
+    // text starts transparent so it isn't drawn before it has been positioned
+    let ui_text = UiText::new(
+        font,
+        text,
+        [color[0], color[1], color[2], 0.0],
+        font_size,
+        LineMode::Single,
+        Anchor::Middle,
+    );
+
+    lazy_update
+        .create_entity(entities)
+        .with(DamageNumberTag)
+        .with(local_transform)
+        .with(motion_2d)
+        .with(fade)
+        .with(Tint(Srgba::new(color[0], color[1], color[2], 1.0)))
+        .with(ui_transform)
+        .with(ui_text)
+        .with(TimeLimitComponent {
+            duration: DAMAGE_NUMBER_DURATION,
+        })
+        .build();
+}
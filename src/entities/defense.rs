@@ -1,5 +1,5 @@
 use crate::{
-    components::{DefenseTag, HealthComponent},
+    components::{DefenseTag, HealthComponent, Resistances},
     constants::DEFENSE,
 };
 use amethyst::{
@@ -15,6 +15,8 @@ pub fn initialize_defense(world: &mut World) {
             value: DEFENSE,
             max_value: DEFENSE,
             armor: 0,
+            resistances: Resistances::default(),
+            shield: None,
         })
         .build();
 }
@@ -1,5 +1,5 @@
 use crate::{
-    components::{EnemySpawnerTag, SpawnerComponent},
+    components::{EnemySpawnerTag, SpawnProbabilities, SpawnerComponent},
     constants::{
         ARENA_MAX_Y, ARENA_MIN_X, ARENA_WIDTH, ENEMY_DRONE_RATIO, ENEMY_HAULER_RATIO,
         ENEMY_MISSILE_LAUNCHER_RATIO, ENEMY_PAWN_RATIO, ENEMY_SPAWN_INTERVAL, ENEMY_STRAFER_RATIO,
@@ -23,35 +23,40 @@ pub fn initialize_enemy_spawner(world: &mut World) {
     world
         .create_entity()
         .with(SpawnerComponent::new(
-            vec![
-                (
-                    Some(SpawnableType::Enemy(EnemyType::Pawn)),
-                    ENEMY_PAWN_RATIO,
-                ),
-                (
-                    Some(SpawnableType::Enemy(EnemyType::Drone)),
-                    ENEMY_DRONE_RATIO,
-                ),
-                (
-                    Some(SpawnableType::Enemy(EnemyType::Hauler)),
-                    ENEMY_HAULER_RATIO,
-                ),
-                (
-                    Some(SpawnableType::Enemy(EnemyType::StraferRight)),
-                    ENEMY_STRAFER_RATIO,
-                ),
-                (
-                    Some(SpawnableType::Enemy(EnemyType::StraferLeft)),
-                    ENEMY_STRAFER_RATIO,
-                ),
-                (
-                    Some(SpawnableType::Enemy(EnemyType::MissileLauncher)),
-                    ENEMY_MISSILE_LAUNCHER_RATIO,
-                ),
-            ],
+            default_enemy_spawn_probabilities(),
             ENEMY_SPAWN_INTERVAL,
         ))
         .with(EnemySpawnerTag)
         .with(local_transform)
         .build();
 }
+
+// enemy probabilities used by phases without their own spawn table
+pub fn default_enemy_spawn_probabilities() -> SpawnProbabilities {
+    vec![
+        (
+            Some(SpawnableType::Enemy(EnemyType::Pawn)),
+            ENEMY_PAWN_RATIO,
+        ),
+        (
+            Some(SpawnableType::Enemy(EnemyType::Drone)),
+            ENEMY_DRONE_RATIO,
+        ),
+        (
+            Some(SpawnableType::Enemy(EnemyType::Hauler)),
+            ENEMY_HAULER_RATIO,
+        ),
+        (
+            Some(SpawnableType::Enemy(EnemyType::StraferRight)),
+            ENEMY_STRAFER_RATIO,
+        ),
+        (
+            Some(SpawnableType::Enemy(EnemyType::StraferLeft)),
+            ENEMY_STRAFER_RATIO,
+        ),
+        (
+            Some(SpawnableType::Enemy(EnemyType::MissileLauncher)),
+            ENEMY_MISSILE_LAUNCHER_RATIO,
+        ),
+    ]
+}
@@ -0,0 +1,83 @@
+use crate::{
+    constants::{
+        INVENTORY_ICONS_PER_ROW, INVENTORY_ICON_SPACING, INVENTORY_ICON_X, INVENTORY_ICON_Y,
+        INVENTORY_ICON_Z, SYNERGY_COLOR, SYNERGY_LABEL_SPACING, SYNERGY_LABEL_Y,
+    },
+    entities::ItemType,
+    resources::{ItemsResource, SpriteSheetsResource},
+};
+use amethyst::{
+    core::transform::Transform,
+    ecs::prelude::{Builder, Entities, LazyUpdate, ReadExpect},
+    renderer::{SpriteRender, Transparent},
+    ui::{Anchor, FontHandle, LineMode, UiText, UiTransform},
+};
+
+// icons fill the left side panel in rows from the bottom up
+pub fn spawn_inventory_icon(
+    item_type: &ItemType,
+    inventory_index: usize,
+    items_resource: &ReadExpect<ItemsResource>,
+    spritesheets_resource: &ReadExpect<SpriteSheetsResource>,
+    entities: &Entities,
+    lazy_update: &ReadExpect<LazyUpdate>,
+) {
+    let item_data = &items_resource.item_entities[item_type];
+
+    let sprite_render = SpriteRender {
+        sprite_sheet: spritesheets_resource.spritesheets[&item_data.sprite_render_data.spritesheet]
+            .clone(),
+        sprite_number: item_data.sprite_render_data.initial_index,
+    };
+
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(
+        INVENTORY_ICON_X
+            + (inventory_index % INVENTORY_ICONS_PER_ROW) as f32 * INVENTORY_ICON_SPACING,
+        INVENTORY_ICON_Y
+            + (inventory_index / INVENTORY_ICONS_PER_ROW) as f32 * INVENTORY_ICON_SPACING,
+        INVENTORY_ICON_Z,
+    );
+
+    lazy_update
+        .create_entity(entities)
+        .with(sprite_render)
+        .with(transform)
+        .with(Transparent)
+        .build();
+}
+
+// active synergies are listed by name in the left side panel
+pub fn spawn_synergy_label(
+    synergy_name: &str,
+    synergy_index: usize,
+    font: FontHandle,
+    entities: &Entities,
+    lazy_update: &ReadExpect<LazyUpdate>,
+) {
+    let ui_transform = UiTransform::new(
+        "synergy_label".to_string(),
+        Anchor::TopLeft,
+        Anchor::TopLeft,
+        6.0,
+        SYNERGY_LABEL_Y - synergy_index as f32 * SYNERGY_LABEL_SPACING,
+        0.9,
+        110.0,
+        SYNERGY_LABEL_SPACING,
+    );
+
+    let ui_text = UiText::new(
+        font,
+        synergy_name.to_string(),
+        SYNERGY_COLOR,
+        12.0,
+        LineMode::Single,
+        Anchor::MiddleLeft,
+    );
+
+    lazy_update
+        .create_entity(entities)
+        .with(ui_transform)
+        .with(ui_text)
+        .build();
+}
@@ -5,8 +5,10 @@ pub mod barriers;
 pub mod blast;
 pub mod boss;
 pub mod consumable;
+pub mod damage_number;
 pub mod defense;
 pub mod enemy_spawner;
+pub mod inventory_icon;
 pub mod planet;
 pub mod side_panels;
 pub mod spaceship;
@@ -14,22 +16,26 @@ pub mod spawn;
 pub mod status_bar;
 pub mod status_unit;
 pub mod store_icons;
+pub mod toast;
 
 pub use self::{
     backgrounds::initialize_background,
     barriers::initialize_arena_barriers,
-    blast::spawn_blasts,
+    blast::{spawn_blast_fragments, spawn_blasts},
     boss::spawn_repeater,
     consumable::spawn_random_consumable,
+    damage_number::spawn_damage_number,
     defense::initialize_defense,
-    enemy_spawner::initialize_enemy_spawner,
+    enemy_spawner::{default_enemy_spawn_probabilities, initialize_enemy_spawner},
+    inventory_icon::{spawn_inventory_icon, spawn_synergy_label},
     planet::initialize_planet,
     side_panels::initialize_side_panels,
     spaceship::initialize_spaceship,
     spawn::{spawn_consumable, spawn_effect, spawn_enemy, spawn_item, spawn_spawnable},
     status_bar::initialize_status_bars,
     status_unit::spawn_status_unit,
-    store_icons::initialize_store_icons,
+    store_icons::{initialize_store_icons, store_slot_position},
+    toast::spawn_toast,
 };
 
 #[derive(Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
@@ -64,6 +70,12 @@ pub enum ConsumableType {
     Money5,
     HealthWrench,
     Armor,
+    Bomb,
+    RapidFire,
+    DoubleDamage,
+    Magnet,
+    Invulnerability,
+    SlowEnemies,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
@@ -81,6 +93,14 @@ pub enum ItemType {
     StructureReinforcement,
     BlasterSizeEnhancer,
     FrequencyAugmentor,
+    TungstenTips,
+    RicochetCoil,
+    SeekerChip,
+    FractalCore,
+    OrdnanceRack,
+    ShieldCapacitor,
+    FluxRegulator,
+    TractorBeam,
 }
 #[derive(Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
 pub enum EffectType {
@@ -1,7 +1,8 @@
 use crate::{
     components::{
         AbilityDirection, BarrelRollAbilityComponent, BlastType, BlasterComponent, HealthComponent,
-        Hitbox2DComponent, ManualFireComponent, Motion2DComponent,
+        Hitbox2DComponent, InventoryComponent, ManualFireComponent, Motion2DComponent,
+        PowerUpsComponent, Resistances,
     },
     constants::{
         ARENA_HEIGHT, ARENA_MIN_X, ARENA_MIN_Y, ARENA_WIDTH, CRIT_BLAST_SPRITE_INDEX,
@@ -11,7 +12,7 @@ use crate::{
         SPACESHIP_DECELERATION_Y, SPACESHIP_FIRE_SPEED, SPACESHIP_HEALTH, SPACESHIP_HITBOX_HEIGHT,
         SPACESHIP_HITBOX_WIDTH, SPACESHIP_MAX_KNOCKBACK_SPEED, SPACESHIP_MAX_SPEED,
     },
-    resources::PlayersResource,
+    resources::{GameParametersResource, PlayersResource, RunSummaryResource},
 };
 use amethyst::{
     assets::Handle,
@@ -23,11 +24,19 @@ use amethyst::{
 use std::collections::HashMap;
 
 pub fn initialize_spaceship(world: &mut World, sprite_sheet_handle: Handle<SpriteSheet>) {
-    let player = {
+    let mut player_data = {
         let players_resource = world.read_resource::<PlayersResource>();
-        players_resource["juggernaut"].player_component.clone()
+        let run_summary = world.read_resource::<RunSummaryResource>();
+        players_resource[&run_summary.character].clone()
     };
 
+    player_data.player_component.money = (player_data.player_component.money as f32
+        * world
+            .read_resource::<GameParametersResource>()
+            .difficulty_preset()
+            .starting_money)
+        .round() as usize;
+
     let mut local_transform = Transform::default();
     local_transform.set_translation_xyz(
         ARENA_MIN_X + (ARENA_WIDTH / 2.0),
@@ -78,18 +87,26 @@ pub fn initialize_spaceship(world: &mut World, sprite_sheet_handle: Handle<Sprit
         crit_chance: 0.0,
         size_multiplier: 1.0,
         spacing: 7.0,
+        pierce: 0,
+        bounce: 0,
+        homing: 0.0,
+        split: 0,
     };
 
     let manual_fire = ManualFireComponent {
         period: SPACESHIP_FIRE_SPEED,
         timer: 0.0,
         ready: false,
+        fire_mode: player_data.fire_mode,
+        charge_timer: 0.0,
     };
 
     let health = HealthComponent {
         value: SPACESHIP_HEALTH,
         max_value: SPACESHIP_HEALTH,
         armor: 0,
+        resistances: Resistances::default(),
+        shield: player_data.shield,
     };
 
     let barrel_roll_ability = BarrelRollAbilityComponent {
@@ -102,7 +119,7 @@ pub fn initialize_spaceship(world: &mut World, sprite_sheet_handle: Handle<Sprit
         steel_barrel: false,
     };
 
-    world
+    let mut spaceship_entity_builder = world
         .create_entity()
         .with(sprite_render)
         .with(barrel_roll_ability)
@@ -113,6 +130,13 @@ pub fn initialize_spaceship(world: &mut World, sprite_sheet_handle: Handle<Sprit
         .with(health)
         .with(local_transform)
         .with(Transparent)
-        .with(player)
-        .build();
+        .with(InventoryComponent::default())
+        .with(PowerUpsComponent::default())
+        .with(player_data.player_component);
+
+    if let Some(secondary_fire_component) = player_data.secondary_fire_component {
+        spaceship_entity_builder = spaceship_entity_builder.with(secondary_fire_component);
+    }
+
+    spaceship_entity_builder.build();
 }
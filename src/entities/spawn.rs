@@ -1,4 +1,5 @@
 use crate::{
+    components::EffectTag,
     entities::{ConsumableType, EffectType, EnemyType, ItemType, SpawnableType},
     resources::{
         ConsumablesResource, EffectsResource, EnemiesResource, ItemsResource, SpriteSheetsResource,
@@ -163,6 +164,7 @@ pub fn spawn_effect(
             .with(sprite_render)
             .with(spawn_transform.clone())
             .with(Transparent)
+            .with(EffectTag)
             .build();
 
         // TODO: scale to be a vector (like with Sprite render)
@@ -3,7 +3,7 @@ use crate::{
     constants::{
         DEFENSE_BAR_LIMIT, DEFENSE_BAR_X, DEFENSE_BAR_Y, HEALTH_BAR_LIMIT, HEALTH_BAR_X,
         HEALTH_BAR_Y, RESTOCK_BAR_LIMIT, RESTOCK_BAR_X, RESTOCK_BAR_Y, ROLL_BAR_LIMIT, ROLL_BAR_X,
-        ROLL_BAR_Y,
+        ROLL_BAR_Y, SHIELD_BAR_LIMIT, SHIELD_BAR_X, SHIELD_BAR_Y,
     },
 };
 use amethyst::{
@@ -24,6 +24,18 @@ pub fn initialize_status_bars(world: &mut World) {
         })
         .build();
 
+    //shield bar
+    world
+        .create_entity()
+        .with(StatusBarComponent {
+            status_type: StatusType::Shield,
+            x_pos: SHIELD_BAR_X,
+            y_pos: SHIELD_BAR_Y,
+            status_unit_stack: vec![],
+            unit_limit: SHIELD_BAR_LIMIT,
+        })
+        .build();
+
     //defense bar
     world
         .create_entity()
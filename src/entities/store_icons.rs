@@ -1,6 +1,9 @@
 use crate::{
     components::StoreIconComponent,
-    constants::{ARENA_MAX_X, ARENA_MIN_Y},
+    constants::{
+        ARENA_MAX_X, ARENA_MIN_Y, STORE_SLOTS_PER_COLUMN, STORE_SLOT_SPACING, STORE_SLOT_X,
+        STORE_SLOT_Y, STORE_SLOT_Z,
+    },
 };
 use amethyst::{
     assets::Handle,
@@ -10,43 +13,37 @@ use amethyst::{
     renderer::{SpriteRender, SpriteSheet},
 };
 
-pub fn initialize_store_icons(world: &mut World, sprite_sheet_handle: Handle<SpriteSheet>) {
-    let store_icon_1 = StoreIconComponent { inventory_index: 0 };
-    let store_icon_2 = StoreIconComponent { inventory_index: 1 };
-    let store_icon_3 = StoreIconComponent { inventory_index: 2 };
+// position of a store slot in the right side panel
+pub fn store_slot_position(inventory_index: usize) -> (f32, f32) {
+    let column = inventory_index / STORE_SLOTS_PER_COLUMN;
+    let row = inventory_index % STORE_SLOTS_PER_COLUMN;
 
+    (
+        ARENA_MAX_X + STORE_SLOT_X + column as f32 * STORE_SLOT_SPACING,
+        ARENA_MIN_Y + STORE_SLOT_Y - row as f32 * STORE_SLOT_SPACING,
+    )
+}
+
+pub fn initialize_store_icons(
+    world: &mut World,
+    sprite_sheet_handle: Handle<SpriteSheet>,
+    slot_count: usize,
+) {
     let sprite_render = SpriteRender {
         sprite_sheet: sprite_sheet_handle,
         sprite_number: 0, // index of the blank item icon on the spritesheet
     };
 
-    let mut transform_1 = Transform::default();
-    transform_1.set_translation_xyz(ARENA_MAX_X + 12.0, ARENA_MIN_Y + 72.0, 0.9);
-
-    let mut transform_2 = transform_1.clone();
-    transform_2.set_translation_y(ARENA_MIN_Y + 53.0);
-
-    let mut transform_3 = transform_1.clone();
-    transform_3.set_translation_y(ARENA_MIN_Y + 34.0);
-
-    world
-        .create_entity()
-        .with(store_icon_1)
-        .with(transform_1)
-        .with(sprite_render.clone())
-        .build();
-
-    world
-        .create_entity()
-        .with(store_icon_2)
-        .with(transform_2)
-        .with(sprite_render.clone())
-        .build();
-
-    world
-        .create_entity()
-        .with(store_icon_3)
-        .with(transform_3)
-        .with(sprite_render)
-        .build();
+    for inventory_index in 0..slot_count {
+        let (x, y) = store_slot_position(inventory_index);
+        let mut transform = Transform::default();
+        transform.set_translation_xyz(x, y, STORE_SLOT_Z);
+
+        world
+            .create_entity()
+            .with(StoreIconComponent { inventory_index })
+            .with(transform)
+            .with(sprite_render.clone())
+            .build();
+    }
 }
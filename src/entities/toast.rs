@@ -0,0 +1,41 @@
+use crate::{
+    components::{TimeLimitComponent, ToastTag},
+    constants::{TOAST_DURATION, TOAST_SPACING},
+};
+use amethyst::{
+    ecs::prelude::{Builder, Entities, LazyUpdate, ReadExpect},
+    ui::{Anchor, FontHandle, LineMode, UiText, UiTransform},
+};
+
+// short notification at the top of the screen
+pub fn spawn_toast(
+    text: String,
+    color: [f32; 4],
+    stack_idx: usize, // toasts already on screen, newer toasts are placed below them
+    font: FontHandle,
+    entities: &Entities,
+    lazy_update: &ReadExpect<LazyUpdate>,
+) {
+    let ui_transform = UiTransform::new(
+        "toast".to_string(),
+        Anchor::TopMiddle,
+        Anchor::TopMiddle,
+        0.0,
+        -40.0 - stack_idx as f32 * TOAST_SPACING,
+        1.0,
+        600.0,
+        TOAST_SPACING,
+    );
+
+    let ui_text = UiText::new(font, text, color, 16.0, LineMode::Single, Anchor::Middle);
+
+    lazy_update
+        .create_entity(entities)
+        .with(ToastTag)
+        .with(ui_transform)
+        .with(ui_text)
+        .with(TimeLimitComponent {
+            duration: TOAST_DURATION,
+        })
+        .build();
+}
@@ -1,3 +1,4 @@
+use crate::{components::DamageKind, entities::ItemType, resources::PhaseType};
 use amethyst::{audio::SourceHandle, core::math::Vector2, ecs::prelude::Entity};
 use std::collections::HashMap;
 
@@ -98,6 +99,7 @@ pub struct ItemGetEvent {
     pub player_entity: Entity,
     pub stat_effects: HashMap<String, f32>,
     pub bool_effects: HashMap<String, bool>,
+    pub item_type: Option<ItemType>, // none for effects that don't come from picking up an item
 }
 
 impl ItemGetEvent {
@@ -105,11 +107,13 @@ impl ItemGetEvent {
         player_entity: Entity,
         stat_effects: HashMap<String, f32>,
         bool_effects: HashMap<String, bool>,
+        item_type: Option<ItemType>,
     ) -> ItemGetEvent {
         ItemGetEvent {
             player_entity,
             stat_effects,
             bool_effects,
+            item_type,
         }
     }
 }
@@ -125,7 +129,102 @@ impl EnemyReachedBottomEvent {
     }
 }
 
+#[derive(Debug)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub source: Option<Entity>,
+    pub amount: f32,
+    pub kind: DamageKind,
+    pub critical: bool,
+}
+
+impl DamageEvent {
+    pub fn new(
+        target: Entity,
+        source: Option<Entity>,
+        amount: f32,
+        kind: DamageKind,
+        critical: bool,
+    ) -> DamageEvent {
+        DamageEvent {
+            target,
+            source,
+            amount,
+            kind,
+            critical,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EntityDamagedEvent {
+    pub target: Entity,
+    pub source: Option<Entity>,
+    pub amount: f32,
+    pub kind: DamageKind,
+    pub critical: bool,
+}
+
+impl EntityDamagedEvent {
+    pub fn new(
+        target: Entity,
+        source: Option<Entity>,
+        amount: f32,
+        kind: DamageKind,
+        critical: bool,
+    ) -> EntityDamagedEvent {
+        EntityDamagedEvent {
+            target,
+            source,
+            amount,
+            kind,
+            critical,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct PlayAudioEvent {
     pub source: SourceHandle,
 }
+
+#[derive(Debug)]
+pub struct PhaseChangedEvent {
+    pub previous_phase: usize,
+    pub phase_idx: usize,
+    pub phase_type: PhaseType,
+    pub loop_count: usize,
+}
+
+impl PhaseChangedEvent {
+    pub fn new(
+        previous_phase: usize,
+        phase_idx: usize,
+        phase_type: PhaseType,
+        loop_count: usize,
+    ) -> PhaseChangedEvent {
+        PhaseChangedEvent {
+            previous_phase,
+            phase_idx,
+            phase_type,
+            loop_count,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RunEndedEvent {
+    pub score: usize,
+    pub phase_idx: usize,
+    pub loop_count: usize,
+}
+
+impl RunEndedEvent {
+    pub fn new(score: usize, phase_idx: usize, loop_count: usize) -> RunEndedEvent {
+        RunEndedEvent {
+            score,
+            phase_idx,
+            loop_count,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 mod events;
 
 pub use self::events::{
-    ArenaBorderCollisionEvent, CollisionEvent, EnemyCollisionEvent, EnemyDestroyedEvent,
-    EnemyReachedBottomEvent, ItemGetEvent, PlayAudioEvent, PlayerCollisionEvent,
+    ArenaBorderCollisionEvent, CollisionEvent, DamageEvent, EnemyCollisionEvent,
+    EnemyDestroyedEvent, EnemyReachedBottomEvent, EntityDamagedEvent, ItemGetEvent,
+    PhaseChangedEvent, PlayAudioEvent, PlayerCollisionEvent, RunEndedEvent,
 };
@@ -29,11 +29,12 @@ pub mod states;
 pub mod systems;
 
 use resources::{
-    ConsumablesResource, DebugLinesConfig, EffectsResource, EnemiesResource, FormationsResource,
-    GameParametersResource, ItemsResource, PhaseManagerResource, PlayersResource, SoundsConfig,
-    SpriteSheetsConfig, StoreResource,
+    AchievementProgressResource, AchievementsResource, ConsumablesResource, DebugLinesConfig,
+    EffectsResource, EnemiesResource, FormationsResource, GameParametersResource, ItemsResource,
+    PhaseManagerResource, PlayersResource, ProfileResource, SoundsConfig, SpriteSheetsConfig,
+    StoreResource, SynergiesResource, UnlocksResource,
 };
-use states::MainGameState;
+use states::DifficultySelectState;
 
 use amethyst::config::Config;
 
@@ -70,6 +71,9 @@ fn main() -> amethyst::Result<()> {
         store,
         game_parameters,
         formations,
+        unlocks,
+        achievements,
+        synergies,
     } = load_include_data();
 
     let items =
@@ -84,12 +88,37 @@ fn main() -> amethyst::Result<()> {
         .expect("failed to load data file: players.ron");
     let phases = <PhaseManagerResource as Config>::load_bytes(phases)
         .expect("failed to load data file: phases.ron");
+    if let Err(error) = phases.validate() {
+        panic!("invalid data file: phases.ron: {}", error);
+    }
     let store =
         <StoreResource as Config>::load_bytes(store).expect("failed to load data file: store.ron");
-    let game_parameters = <GameParametersResource as Config>::load_bytes(game_parameters)
+    if let Err(error) = store.validate() {
+        panic!("invalid data file: store.ron: {}", error);
+    }
+    let mut game_parameters = <GameParametersResource as Config>::load_bytes(game_parameters)
         .expect("failed to load data file: game_parameters.ron");
+
+    // the character can be chosen with `--character <name>`
+    let mut args = std::env::args();
+    while let Some(arg) = args.next() {
+        if arg == "--character" {
+            game_parameters.character = args.next();
+        }
+    }
     let formations = <FormationsResource as Config>::load_bytes(formations)
         .expect("failed to load data file: formations.ron");
+    if let Err(error) = formations.validate(&phases) {
+        panic!("invalid data file: formations.ron: {}", error);
+    }
+    let unlocks = <UnlocksResource as Config>::load_bytes(unlocks)
+        .expect("failed to load data file: unlocks.ron");
+    let profile = ProfileResource::load();
+    let achievements = <AchievementsResource as Config>::load_bytes(achievements)
+        .expect("failed to load data file: achievements.ron");
+    let achievement_progress = AchievementProgressResource::load();
+    let synergies = <SynergiesResource as Config>::load_bytes(synergies)
+        .expect("failed to load data file: synergies.ron");
 
     let game_data = GameDataBuilder::default()
         .with_system_desc(GltfSceneLoaderSystemDesc::default(), "gltf_system", &[])
@@ -109,7 +138,7 @@ fn main() -> amethyst::Result<()> {
                 .with_plugin(RenderDebugLines::default()),
         )?;
 
-    let mut game = Application::build(app_root.join("assets"), MainGameState::default())?
+    let mut game = Application::build(app_root.join("assets"), DifficultySelectState::default())?
         .with_resource(items)
         .with_resource(enemies)
         .with_resource(consumables)
@@ -122,6 +151,11 @@ fn main() -> amethyst::Result<()> {
         .with_resource(store)
         .with_resource(game_parameters)
         .with_resource(formations)
+        .with_resource(unlocks)
+        .with_resource(profile)
+        .with_resource(achievements)
+        .with_resource(achievement_progress)
+        .with_resource(synergies)
         .build(game_data)?;
 
     game.run();
@@ -0,0 +1,96 @@
+use crate::{constants::ACHIEVEMENTS_FILE, data_include::config_file_path, entities::EnemyType};
+use amethyst::config::Config;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fs};
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum AchievementRule {
+    EnemiesDestroyed(usize), // counted across runs
+    EnemyTypeDestroyed(EnemyType, usize),
+    ItemsCollected(usize),
+    EnemiesRammed(usize),
+    RunScore(usize),           // score when a run ends
+    PhaseWithoutDamage(usize), // reach a phase without the player taking damage
+    PhaseWithoutBreach(usize), // reach a phase without an enemy reaching the bottom of the arena
+}
+
+impl AchievementRule {
+    // count needed by rules that are progressed across runs
+    pub fn target_count(&self) -> Option<usize> {
+        match self {
+            AchievementRule::EnemiesDestroyed(count)
+            | AchievementRule::EnemyTypeDestroyed(_, count)
+            | AchievementRule::ItemsCollected(count)
+            | AchievementRule::EnemiesRammed(count) => Some(*count),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Achievement {
+    pub name: String,
+    pub description: String,
+    pub rule: AchievementRule,
+}
+
+// achievement rules, read from achievements.ron
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct AchievementsResource {
+    pub achievements: Vec<Achievement>,
+}
+
+// achievement progress kept between runs
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct AchievementProgressResource {
+    pub counts: HashMap<String, usize>, // progress of count rules by achievement name
+    pub unlocked: Vec<String>,
+}
+
+impl AchievementProgressResource {
+    // loads progress from the config directory, a missing or unreadable file starts over and
+    // unreadable files are kept next to it so they aren't overwritten
+    pub fn load() -> Self {
+        let path = config_file_path(ACHIEVEMENTS_FILE);
+
+        if !path.is_file() {
+            return AchievementProgressResource::default();
+        }
+
+        match <AchievementProgressResource as Config>::load(&path) {
+            Ok(progress) => progress,
+            Err(error) => {
+                log::warn!(
+                    "unable to read achievement progress, starting over: {}",
+                    error
+                );
+
+                if fs::rename(&path, path.with_extension("ron.corrupt")).is_err() {
+                    log::warn!("unable to back up unreadable achievements file");
+                }
+
+                AchievementProgressResource::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = self.write(config_file_path(ACHIEVEMENTS_FILE)) {
+            log::error!("unable to save achievement progress: {}", error);
+        }
+    }
+
+    pub fn is_unlocked(&self, achievement: &Achievement) -> bool {
+        self.unlocked.contains(&achievement.name)
+    }
+
+    pub fn count(&self, achievement: &Achievement) -> usize {
+        self.counts.get(&achievement.name).cloned().unwrap_or(0)
+    }
+
+    pub fn add_count(&mut self, achievement: &Achievement, amount: usize) {
+        if amount > 0 {
+            *self.counts.entry(achievement.name.clone()).or_insert(0) += amount;
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use crate::{
-    entities::{spawn::spawn_spawnable, SpawnableType},
+    components::{PathFollowerComponent, PathType},
+    entities::{
+        spawn::{spawn_enemy, spawn_spawnable},
+        SpawnableType,
+    },
     resources::{
-        ConsumablesResource, EffectsResource, EnemiesResource, ItemsResource, SpriteSheetsResource,
+        ConsumablesResource, EffectsResource, EnemiesResource, ItemsResource, PhaseManagerResource,
+        SpriteSheetsResource,
     },
 };
 use amethyst::{
@@ -9,9 +14,10 @@ use amethyst::{
     ecs::prelude::{Entities, LazyUpdate, ReadExpect},
 };
 
-use rand::seq::SliceRandom;
+use rand::seq::{IteratorRandom, SliceRandom};
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct FormationSpawnable {
@@ -19,9 +25,24 @@ pub struct FormationSpawnable {
     pub position: Vector2<f32>,
 }
 
+// shared path followed by every enemy in a formation, waypoints are relative to each enemy's spawn position
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct FormationPath {
+    #[serde(default)]
+    pub path_type: PathType,
+    pub waypoints: Vec<Vector2<f32>>,
+    pub speed: f32,
+    #[serde(default)]
+    pub looping: bool,
+    #[serde(default)]
+    pub stagger: f32, // delay between each enemy starting along the path
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Formation {
     pub formation_spawnables: Vec<FormationSpawnable>,
+    #[serde(default)]
+    pub path: Option<FormationPath>,
 }
 
 impl Formation {
@@ -35,6 +56,8 @@ impl Formation {
         entities: &Entities,
         lazy_update: &ReadExpect<LazyUpdate>,
     ) {
+        let mut path_index = 0;
+
         for formation_spawnable in self.formation_spawnables.iter() {
             let mut spawn_transform = Transform::default();
             spawn_transform.set_translation_xyz(
@@ -43,6 +66,35 @@ impl Formation {
                 0.0,
             );
 
+            // enemies follow the formation's path instead of their own movement
+            if let (Some(path), SpawnableType::Enemy(enemy_type)) =
+                (&self.path, &formation_spawnable.spawnable_type)
+            {
+                let enemy_entity = spawn_enemy(
+                    enemy_type,
+                    spawn_transform,
+                    enemies_resource,
+                    spritesheets_resource,
+                    entities,
+                    lazy_update,
+                );
+
+                lazy_update.insert(
+                    enemy_entity,
+                    PathFollowerComponent::new(
+                        &path.path_type,
+                        &path.waypoints,
+                        formation_spawnable.position,
+                        path.speed,
+                        path.looping,
+                        path_index as f32 * path.stagger,
+                    ),
+                );
+
+                path_index += 1;
+                continue;
+            }
+
             spawn_spawnable(
                 &formation_spawnable.spawnable_type,
                 spawn_transform,
@@ -58,16 +110,77 @@ impl Formation {
     }
 }
 
+// how a phase chooses which formations to spawn
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum FormationSequence {
+    // formation names with weights, one is chosen at random every period
+    Pool {
+        formations: Vec<(String, f32)>,
+        period: f32,
+    },
+    // formation names spawned in order, each after its delay in seconds
+    Script(Vec<(String, f32)>),
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct FormationsResource {
-    pub formations: Vec<Formation>,
+    pub formations: HashMap<String, Formation>,
     pub period: f32,
     pub timer: f32,
+    #[serde(default)]
+    pub script_idx: usize,
+    #[serde(default)]
+    pub active_phase: Option<usize>,
 }
 
 impl FormationsResource {
-    pub fn spawn_random_formation_when_ready(
+    // checks that every formation named by the phases exists and that formation pools have
+    // usable weights
+    pub fn validate(&self, phase_manager: &PhaseManagerResource) -> Result<(), String> {
+        for (phase_idx, phase) in phase_manager.phase_map.iter().enumerate() {
+            let names: Vec<&String> = match &phase.formations {
+                Some(FormationSequence::Pool { formations, .. }) => {
+                    if formations.iter().any(|(_, weight)| *weight < 0.0)
+                        || formations.iter().map(|(_, weight)| weight).sum::<f32>() <= 0.0
+                    {
+                        return Err(format!(
+                            "invalid formation pool weights in phase {}",
+                            phase_idx
+                        ));
+                    }
+
+                    formations.iter().map(|(name, _)| name).collect()
+                }
+                Some(FormationSequence::Script(script)) => {
+                    script.iter().map(|(name, _)| name).collect()
+                }
+                None => vec![],
+            };
+
+            for name in names {
+                if !self.formations.contains_key(name) {
+                    return Err(format!(
+                        "phase {} uses unknown formation {}",
+                        phase_idx, name
+                    ));
+                }
+            }
+        }
+
+        if self.formations.is_empty() {
+            return Err("no formations to spawn".to_string());
+        }
+
+        Ok(())
+    }
+
+    // spawns the next formation of the phase's sequence, or a random formation from all formations
+    // if the phase doesn't have a sequence, with times between formations divided by the spawn rate
+    pub fn spawn_phase_formation_when_ready(
         &mut self,
+        phase_idx: usize,
+        formation_sequence: &Option<FormationSequence>,
+        spawn_rate: f32,
         dt: f32,
         consumables_resource: &ReadExpect<ConsumablesResource>,
         enemies_resource: &ReadExpect<EnemiesResource>,
@@ -77,23 +190,75 @@ impl FormationsResource {
         entities: &Entities,
         lazy_update: &ReadExpect<LazyUpdate>,
     ) {
+        // restart the sequence when a new phase begins
+        if self.active_phase != Some(phase_idx) {
+            self.active_phase = Some(phase_idx);
+            self.script_idx = 0;
+            self.timer = match formation_sequence {
+                Some(FormationSequence::Script(script)) => {
+                    script.first().map(|(_, delay)| *delay).unwrap_or(0.0) / spawn_rate
+                }
+                _ => 0.0,
+            };
+        }
+
         self.timer -= dt;
 
-        if self.timer <= 0.0 {
-            self.timer = self.period;
+        if self.timer > 0.0 {
+            return;
+        }
+
+        let formation_name = match formation_sequence {
+            Some(FormationSequence::Pool { formations, period }) => {
+                self.timer = *period / spawn_rate;
 
-            self.formations
-                .choose(&mut rand::thread_rng())
-                .unwrap()
-                .spawn_formation(
-                    consumables_resource,
-                    enemies_resource,
-                    items_resource,
-                    effects_resource,
-                    spritesheets_resource,
-                    entities,
-                    lazy_update,
-                );
+                match formations.choose_weighted(&mut rand::thread_rng(), |(_, weight)| *weight) {
+                    Ok((name, _)) => name.clone(),
+                    Err(error) => {
+                        log::warn!("unable to choose formation from pool: {}", error);
+                        return;
+                    }
+                }
+            }
+
+            Some(FormationSequence::Script(script)) => match script.get(self.script_idx) {
+                Some((name, _)) => {
+                    self.script_idx += 1;
+                    self.timer = script
+                        .get(self.script_idx)
+                        .map(|(_, delay)| *delay)
+                        .unwrap_or(0.0)
+                        / spawn_rate;
+                    name.clone()
+                }
+                // the script is finished for this phase
+                None => return,
+            },
+
+            None => {
+                self.timer = self.period / spawn_rate;
+
+                match self.formations.keys().choose(&mut rand::thread_rng()) {
+                    Some(name) => name.clone(),
+                    None => {
+                        log::warn!("no formations to spawn");
+                        return;
+                    }
+                }
+            }
+        };
+
+        match self.formations.get(&formation_name) {
+            Some(formation) => formation.spawn_formation(
+                consumables_resource,
+                enemies_resource,
+                items_resource,
+                effects_resource,
+                spritesheets_resource,
+                entities,
+                lazy_update,
+            ),
+            None => log::warn!("formation {} does not exist", formation_name),
         }
     }
 }
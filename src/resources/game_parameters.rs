@@ -1,6 +1,95 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub enum DifficultyType {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Default for DifficultyType {
+    fn default() -> Self {
+        DifficultyType::Normal
+    }
+}
+
+// multipliers for a difficulty chosen at the start of a run
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct DifficultyPreset {
+    pub enemy_health: f32,
+    pub enemy_damage: f32,
+    pub defense_damage: f32, // damage to the defense from enemies reaching the bottom of the arena
+    pub starting_money: f32,
+    pub store_prices: f32,
+    pub restock_period: f32,
+}
+
+impl Default for DifficultyPreset {
+    fn default() -> Self {
+        DifficultyPreset {
+            enemy_health: 1.0,
+            enemy_damage: 1.0,
+            defense_damage: 1.0,
+            starting_money: 1.0,
+            store_prices: 1.0,
+            restock_period: 1.0,
+        }
+    }
+}
+
+// multipliers applied to enemies and spawning
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct DifficultyMultipliers {
+    pub health: f32,
+    pub damage: f32,
+    pub speed: f32,
+    pub spawn_rate: f32,
+    pub loot: f32,
+}
+
+impl Default for DifficultyMultipliers {
+    fn default() -> Self {
+        DifficultyMultipliers {
+            health: 1.0,
+            damage: 1.0,
+            speed: 1.0,
+            spawn_rate: 1.0,
+            loot: 1.0,
+        }
+    }
+}
 
 #[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct GameParametersResource {
     pub min_collision_knockback: f32,
+    #[serde(default)]
+    pub endless_mode: bool, // loop the phase map after the last phase
+    #[serde(default)]
+    pub difficulty_curve: Vec<DifficultyMultipliers>, // multipliers for each loop of the phase map
+    #[serde(default)]
+    pub difficulty: DifficultyType,
+    #[serde(default)]
+    pub difficulty_presets: HashMap<DifficultyType, DifficultyPreset>,
+    #[serde(default)]
+    pub character: Option<String>, // character chosen for the run, the default character otherwise
+}
+
+impl GameParametersResource {
+    // preset of the selected difficulty, difficulties without a preset don't change anything
+    pub fn difficulty_preset(&self) -> DifficultyPreset {
+        self.difficulty_presets
+            .get(&self.difficulty)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    // multipliers for the given loop, loops past the end of the curve use its last entry
+    pub fn difficulty(&self, loop_count: usize) -> DifficultyMultipliers {
+        self.difficulty_curve
+            .get(loop_count)
+            .or_else(|| self.difficulty_curve.last())
+            .cloned()
+            .unwrap_or_default()
+    }
 }
@@ -0,0 +1,163 @@
+use crate::{
+    constants::{HIGH_SCORES_FILE, MAX_HIGH_SCORES},
+    data_include::config_file_path,
+    resources::DifficultyType,
+};
+use amethyst::config::Config;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct HighScoreEntry {
+    pub name: String,
+    pub score: usize,
+    pub character: String,
+    #[serde(alias = "seed")]
+    pub run_id: u64,
+    pub phase_reached: usize,
+    #[serde(default)]
+    pub loop_reached: usize,
+    pub difficulty: DifficultyType,
+    #[serde(default)]
+    pub endless_mode: bool,
+    pub timestamp: u64, // seconds since the unix epoch
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct HighScoresResource {
+    pub entries: Vec<HighScoreEntry>,
+}
+
+impl HighScoresResource {
+    // loads the high score table from the config directory, a missing or unreadable file starts an
+    // empty table and unreadable files are kept next to it so they aren't overwritten
+    pub fn load() -> Self {
+        let path = config_file_path(HIGH_SCORES_FILE);
+
+        if !path.is_file() {
+            return HighScoresResource::default();
+        }
+
+        match <HighScoresResource as Config>::load(&path) {
+            Ok(mut high_scores) => {
+                high_scores.sort();
+                high_scores
+            }
+            Err(error) => {
+                log::warn!(
+                    "unable to read high scores, starting a new table: {}",
+                    error
+                );
+
+                if fs::rename(&path, path.with_extension("ron.corrupt")).is_err() {
+                    log::warn!("unable to back up unreadable high scores file");
+                }
+
+                HighScoresResource::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = self.write(config_file_path(HIGH_SCORES_FILE)) {
+            log::error!("unable to save high scores: {}", error);
+        }
+    }
+
+    // adds an entry to the table, returns its rank if it made the table
+    pub fn insert(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        let rank = self
+            .entries
+            .iter()
+            .position(|other| entry.score > other.score)
+            .unwrap_or_else(|| self.entries.len());
+
+        if rank >= MAX_HIGH_SCORES {
+            return None;
+        }
+
+        self.entries.insert(rank, entry);
+        self.entries.truncate(MAX_HIGH_SCORES);
+        Some(rank)
+    }
+
+    fn sort(&mut self) {
+        self.entries.sort_by(|a, b| b.score.cmp(&a.score));
+        self.entries.truncate(MAX_HIGH_SCORES);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn insert(high_scores: &mut HighScoresResource, score: usize) -> Option<usize> {
+        high_scores.insert(HighScoreEntry {
+            score,
+            ..HighScoreEntry::default()
+        })
+    }
+
+    fn scores(high_scores: &HighScoresResource) -> Vec<usize> {
+        high_scores
+            .entries
+            .iter()
+            .map(|entry| entry.score)
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_keeps_entries_sorted() {
+        let mut high_scores = HighScoresResource::default();
+
+        assert_eq!(insert(&mut high_scores, 200), Some(0));
+        assert_eq!(insert(&mut high_scores, 300), Some(0));
+        assert_eq!(insert(&mut high_scores, 100), Some(2));
+        assert_eq!(insert(&mut high_scores, 250), Some(1));
+
+        assert_eq!(scores(&high_scores), vec![300, 250, 200, 100]);
+    }
+
+    #[test]
+    fn test_insert_places_ties_after_existing_entries() {
+        let mut high_scores = HighScoresResource::default();
+        insert(&mut high_scores, 100);
+
+        assert_eq!(insert(&mut high_scores, 100), Some(1));
+    }
+
+    #[test]
+    fn test_insert_truncates_table() {
+        let mut high_scores = HighScoresResource::default();
+        for score in 1..=MAX_HIGH_SCORES {
+            insert(&mut high_scores, score * 10);
+        }
+
+        // scores below the whole table don't make it
+        assert_eq!(insert(&mut high_scores, 5), None);
+        assert_eq!(high_scores.entries.len(), MAX_HIGH_SCORES);
+
+        // the lowest entry drops off the table
+        assert_eq!(insert(&mut high_scores, 1000), Some(0));
+        assert_eq!(high_scores.entries.len(), MAX_HIGH_SCORES);
+        assert_eq!(high_scores.entries.last().unwrap().score, 20);
+    }
+
+    #[test]
+    fn test_sort_orders_and_truncates_loaded_entries() {
+        let mut high_scores = HighScoresResource {
+            entries: (0..MAX_HIGH_SCORES + 2)
+                .map(|score| HighScoreEntry {
+                    score,
+                    ..HighScoreEntry::default()
+                })
+                .collect(),
+        };
+
+        high_scores.sort();
+
+        assert_eq!(high_scores.entries.len(), MAX_HIGH_SCORES);
+        assert_eq!(high_scores.entries[0].score, MAX_HIGH_SCORES + 1);
+        assert_eq!(high_scores.entries.last().unwrap().score, 2);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::components::PlayerComponent;
+use crate::components::{FireMode, PlayerComponent, SecondaryFireComponent, Shield};
 use amethyst::{
     assets::Handle,
     renderer::{palette::Srgba, SpriteSheet},
@@ -6,26 +6,56 @@ use amethyst::{
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub mod achievements;
 pub mod formations;
 pub mod game_parameters;
+pub mod high_scores;
 pub mod phases;
+pub mod profile;
+pub mod run_stats;
+pub mod run_summary;
+pub mod save;
+pub mod score;
 pub mod spawnables;
 pub mod store;
+pub mod synergies;
 
-pub use self::formations::FormationsResource;
-pub use self::game_parameters::GameParametersResource;
-pub use self::phases::{BossType, Phase, PhaseManagerResource, PhaseType};
+pub use self::achievements::{
+    Achievement, AchievementProgressResource, AchievementRule, AchievementsResource,
+};
+pub use self::formations::{FormationSequence, FormationsResource};
+pub use self::game_parameters::{
+    DifficultyMultipliers, DifficultyPreset, DifficultyType, GameParametersResource,
+};
+pub use self::high_scores::{HighScoreEntry, HighScoresResource};
+pub use self::phases::{
+    BossType, Phase, PhaseCondition, PhaseManagerResource, PhaseType, SpawnTable,
+};
+pub use self::profile::{
+    LifetimeStats, ProfileResource, Unlock, UnlockRequirement, UnlockReward, UnlocksResource,
+};
+pub use self::run_stats::{PhaseTimeRecord, PurchaseRecord, RunStatsResource};
+pub use self::run_summary::RunSummaryResource;
+pub use self::save::SaveGame;
+pub use self::score::ScoreResource;
 pub use self::spawnables::{
     ConsumableEntityData, ConsumablesResource, EffectEntityData, EffectsResource, EnemiesResource,
     EnemyEntityData, ItemEntityData, ItemsResource,
 };
 pub use self::store::StoreResource;
+pub use self::synergies::{SynergiesResource, Synergy};
 
 pub type PlayersResource = HashMap<String, PlayerEntityData>;
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PlayerEntityData {
     pub player_component: PlayerComponent,
+    #[serde(default)]
+    pub fire_mode: FireMode,
+    #[serde(default)]
+    pub secondary_fire_component: Option<SecondaryFireComponent>,
+    #[serde(default)]
+    pub shield: Option<Shield>,
 }
 
 #[derive(Clone)]
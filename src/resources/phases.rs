@@ -1,3 +1,4 @@
+use crate::{components::SpawnProbabilities, resources::FormationSequence};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -14,12 +15,88 @@ pub enum BossType {
     None,
 }
 
+// enemies spawned by a RandomInvasion phase
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SpawnTable {
+    pub probabilities: SpawnProbabilities,
+    pub period: f32,
+    #[serde(default)]
+    pub end_period: Option<f32>, // period reached by the end of the phase, ramping linearly from period
+}
+
+impl SpawnTable {
+    // spawn period at the given progress (0.0 to 1.0) through the phase
+    pub fn period_at(&self, progress: f32) -> f32 {
+        match self.end_period {
+            Some(end_period) => {
+                self.period + (end_period - self.period) * progress.max(0.0).min(1.0)
+            }
+            None => self.period,
+        }
+    }
+}
+
+// conditions for a phase to end, checked every frame
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum PhaseCondition {
+    TicksElapsed(usize),
+    EnemiesKilled(usize),
+    EnemiesCleared, // no hostile enemies left in the arena
+    BossDefeated,
+    DefenseBelow(f32),
+    Any(Vec<PhaseCondition>),
+    All(Vec<PhaseCondition>),
+}
+
+impl PhaseCondition {
+    pub fn is_met(
+        &self,
+        phase_manager: &PhaseManagerResource,
+        enemies_alive: usize,
+        defense_health: f32,
+    ) -> bool {
+        match self {
+            PhaseCondition::TicksElapsed(ticks) => phase_manager.current_tick >= *ticks,
+            PhaseCondition::EnemiesKilled(kills) => phase_manager.enemies_killed >= *kills,
+            PhaseCondition::EnemiesCleared => enemies_alive == 0,
+            PhaseCondition::BossDefeated => phase_manager.boss_defeated,
+            PhaseCondition::DefenseBelow(value) => defense_health < *value,
+            PhaseCondition::Any(conditions) => conditions
+                .iter()
+                .any(|condition| condition.is_met(phase_manager, enemies_alive, defense_health)),
+            PhaseCondition::All(conditions) => conditions
+                .iter()
+                .all(|condition| condition.is_met(phase_manager, enemies_alive, defense_health)),
+        }
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Phase {
     pub phase_type: PhaseType,
     pub boss_type: BossType,
     pub length: usize,
     pub boss_spawned: bool,
+    #[serde(default)]
+    pub formations: Option<FormationSequence>, // formations used by FormationInvasion phases
+    #[serde(default)]
+    pub spawn_table: Option<SpawnTable>, // enemies used by RandomInvasion phases
+    #[serde(default)]
+    pub end_condition: Option<PhaseCondition>,
+}
+
+impl Phase {
+    // condition for ending the phase, boss phases end when the boss is defeated by default
+    // and other phases after their length in ticks
+    pub fn end_condition(&self) -> PhaseCondition {
+        match &self.end_condition {
+            Some(end_condition) => end_condition.clone(),
+            None => match self.phase_type {
+                PhaseType::Boss => PhaseCondition::BossDefeated,
+                _ => PhaseCondition::TicksElapsed(self.length),
+            },
+        }
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Default)]
@@ -30,10 +107,67 @@ pub struct PhaseManagerResource {
     pub current_tick: usize,
     pub tick_timer: f32,
     pub tick_length: f32,
+    #[serde(default)]
+    pub enemies_killed: usize, // enemies destroyed during the current phase
+    #[serde(default)]
+    pub boss_defeated: bool,
+    #[serde(default)]
+    pub loop_count: usize, // times the phase map has been completed in endless mode
 }
 
 impl PhaseManagerResource {
-    pub fn update(&mut self, dt: f32) {
+    // checks that every phase's spawn table can spawn something, called when loading phases.ron
+    pub fn validate(&self) -> Result<(), String> {
+        for (phase_idx, phase) in self.phase_map.iter().enumerate() {
+            if let Some(spawn_table) = &phase.spawn_table {
+                let probabilities = &spawn_table.probabilities;
+                let total_probability: f32 = probabilities
+                    .iter()
+                    .map(|(_, probability)| probability)
+                    .sum();
+
+                if total_probability <= 0.0
+                    || probabilities
+                        .iter()
+                        .any(|(_, probability)| *probability < 0.0)
+                {
+                    return Err(format!(
+                        "invalid spawn table probabilities in phase {}",
+                        phase_idx
+                    ));
+                }
+
+                if spawn_table.period <= 0.0
+                    || spawn_table.end_period.map_or(false, |period| period <= 0.0)
+                {
+                    return Err(format!("invalid spawn table period in phase {}", phase_idx));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // fraction of the current phase's length that has elapsed
+    pub fn phase_progress(&self) -> f32 {
+        let length = self.phase_map[self.phase_idx].length;
+
+        if length == 0 {
+            0.0
+        } else {
+            self.current_tick as f32 / length as f32
+        }
+    }
+
+    // advances the tick counter and moves to the next phase when the current phase's end condition
+    // is met, returns true if the phase changed
+    pub fn update(
+        &mut self,
+        dt: f32,
+        enemies_alive: usize,
+        defense_health: f32,
+        endless_mode: bool,
+    ) -> bool {
         if self.tick_timer > 0.0 {
             self.tick_timer -= dt;
         } else {
@@ -43,13 +177,206 @@ impl PhaseManagerResource {
             );
             self.tick_timer = self.tick_length;
             self.current_tick += 1;
+        }
 
-            if self.phase_idx < self.last_phase
-                && self.current_tick >= self.phase_map[self.phase_idx].length
-            {
-                self.phase_idx += 1;
-                self.current_tick = 0;
+        if !self.phase_map[self.phase_idx].end_condition().is_met(
+            self,
+            enemies_alive,
+            defense_health,
+        ) {
+            return false;
+        }
+
+        // in endless mode the rest phase after the last boss is skipped so loops wrap right away
+        let next_is_trailing_rest = self.phase_idx + 1 == self.last_phase
+            && match self.phase_map[self.last_phase].phase_type {
+                PhaseType::Rest => true,
+                _ => false,
+            };
+
+        if self.phase_idx < self.last_phase && !(endless_mode && next_is_trailing_rest) {
+            self.phase_idx += 1;
+        } else if endless_mode {
+            // start the phase map over with bosses ready to spawn again
+            self.phase_idx = 0;
+            self.loop_count += 1;
+            for phase in self.phase_map.iter_mut() {
+                phase.boss_spawned = false;
             }
+        } else {
+            return false;
         }
+
+        self.current_tick = 0;
+        self.enemies_killed = 0;
+        self.boss_defeated = false;
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn phase(phase_type: PhaseType, end_condition: PhaseCondition) -> Phase {
+        Phase {
+            phase_type,
+            boss_type: BossType::None,
+            length: 10,
+            boss_spawned: false,
+            formations: None,
+            spawn_table: None,
+            end_condition: Some(end_condition),
+        }
+    }
+
+    #[test]
+    fn test_spawn_table_period_ramps_to_end_period() {
+        let spawn_table = SpawnTable {
+            probabilities: vec![],
+            period: 2.0,
+            end_period: Some(1.0),
+        };
+
+        assert_eq!(spawn_table.period_at(0.0), 2.0);
+        assert_eq!(spawn_table.period_at(0.5), 1.5);
+        assert_eq!(spawn_table.period_at(1.0), 1.0);
+        // progress is clamped to the phase
+        assert_eq!(spawn_table.period_at(-1.0), 2.0);
+        assert_eq!(spawn_table.period_at(2.0), 1.0);
+    }
+
+    #[test]
+    fn test_spawn_table_period_without_end_period() {
+        let spawn_table = SpawnTable {
+            probabilities: vec![],
+            period: 2.0,
+            end_period: None,
+        };
+
+        assert_eq!(spawn_table.period_at(0.0), 2.0);
+        assert_eq!(spawn_table.period_at(1.0), 2.0);
+    }
+
+    #[test]
+    fn test_phase_conditions() {
+        let mut phase_manager = PhaseManagerResource::default();
+        phase_manager.current_tick = 5;
+        phase_manager.enemies_killed = 3;
+
+        assert!(PhaseCondition::TicksElapsed(5).is_met(&phase_manager, 1, 100.0));
+        assert!(!PhaseCondition::TicksElapsed(6).is_met(&phase_manager, 1, 100.0));
+        assert!(PhaseCondition::EnemiesKilled(3).is_met(&phase_manager, 1, 100.0));
+        assert!(!PhaseCondition::EnemiesKilled(4).is_met(&phase_manager, 1, 100.0));
+        assert!(PhaseCondition::EnemiesCleared.is_met(&phase_manager, 0, 100.0));
+        assert!(!PhaseCondition::EnemiesCleared.is_met(&phase_manager, 1, 100.0));
+        assert!(PhaseCondition::DefenseBelow(50.0).is_met(&phase_manager, 1, 40.0));
+        assert!(!PhaseCondition::DefenseBelow(50.0).is_met(&phase_manager, 1, 50.0));
+
+        assert!(!PhaseCondition::BossDefeated.is_met(&phase_manager, 1, 100.0));
+        phase_manager.boss_defeated = true;
+        assert!(PhaseCondition::BossDefeated.is_met(&phase_manager, 1, 100.0));
+    }
+
+    #[test]
+    fn test_combined_phase_conditions() {
+        let phase_manager = PhaseManagerResource::default();
+        let met = PhaseCondition::EnemiesCleared;
+        let not_met = PhaseCondition::TicksElapsed(1);
+
+        let any = PhaseCondition::Any(vec![not_met.clone(), met.clone()]);
+        let all = PhaseCondition::All(vec![not_met.clone(), met.clone()]);
+        assert!(any.is_met(&phase_manager, 0, 100.0));
+        assert!(!all.is_met(&phase_manager, 0, 100.0));
+
+        assert!(PhaseCondition::All(vec![met.clone(), met]).is_met(&phase_manager, 0, 100.0));
+        assert!(!PhaseCondition::Any(vec![not_met]).is_met(&phase_manager, 0, 100.0));
+    }
+
+    #[test]
+    fn test_default_end_conditions() {
+        let mut boss_phase = phase(PhaseType::Boss, PhaseCondition::EnemiesCleared);
+        boss_phase.end_condition = None;
+        let mut rest_phase = phase(PhaseType::Rest, PhaseCondition::EnemiesCleared);
+        rest_phase.end_condition = None;
+
+        match boss_phase.end_condition() {
+            PhaseCondition::BossDefeated => {}
+            end_condition => panic!("unexpected boss end condition {:?}", end_condition),
+        }
+        match rest_phase.end_condition() {
+            PhaseCondition::TicksElapsed(10) => {}
+            end_condition => panic!("unexpected rest end condition {:?}", end_condition),
+        }
+    }
+
+    #[test]
+    fn test_update_advances_when_end_condition_is_met() {
+        let mut phase_manager = PhaseManagerResource {
+            phase_map: vec![
+                phase(PhaseType::Rest, PhaseCondition::EnemiesCleared),
+                phase(PhaseType::Rest, PhaseCondition::EnemiesCleared),
+            ],
+            last_phase: 1,
+            tick_length: 1.0,
+            ..PhaseManagerResource::default()
+        };
+        phase_manager.enemies_killed = 4;
+
+        assert!(!phase_manager.update(0.1, 1, 100.0, false));
+        assert_eq!(phase_manager.phase_idx, 0);
+
+        assert!(phase_manager.update(0.1, 0, 100.0, false));
+        assert_eq!(phase_manager.phase_idx, 1);
+        assert_eq!(phase_manager.current_tick, 0);
+        assert_eq!(phase_manager.enemies_killed, 0);
+
+        // the last phase holds without endless mode
+        assert!(!phase_manager.update(0.1, 0, 100.0, false));
+        assert_eq!(phase_manager.phase_idx, 1);
+    }
+
+    #[test]
+    fn test_endless_mode_skips_trailing_rest_phase() {
+        let mut phase_manager = PhaseManagerResource {
+            phase_map: vec![
+                phase(PhaseType::RandomInvasion, PhaseCondition::EnemiesCleared),
+                phase(PhaseType::Boss, PhaseCondition::EnemiesCleared),
+                phase(PhaseType::Rest, PhaseCondition::EnemiesCleared),
+            ],
+            last_phase: 2,
+            phase_idx: 1,
+            tick_length: 1.0,
+            ..PhaseManagerResource::default()
+        };
+        phase_manager.phase_map[1].boss_spawned = true;
+
+        assert!(phase_manager.update(0.1, 0, 100.0, true));
+        assert_eq!(phase_manager.phase_idx, 0);
+        assert_eq!(phase_manager.loop_count, 1);
+        assert!(!phase_manager.phase_map[1].boss_spawned);
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_spawn_tables() {
+        let mut random_phase = phase(PhaseType::RandomInvasion, PhaseCondition::EnemiesCleared);
+        random_phase.spawn_table = Some(SpawnTable {
+            probabilities: vec![(None, 0.0)],
+            period: 1.0,
+            end_period: None,
+        });
+        let mut phase_manager = PhaseManagerResource {
+            phase_map: vec![random_phase],
+            ..PhaseManagerResource::default()
+        };
+
+        assert!(phase_manager.validate().is_err());
+
+        phase_manager.phase_map[0]
+            .spawn_table
+            .as_mut()
+            .unwrap()
+            .probabilities = vec![(None, 1.0)];
+        assert!(phase_manager.validate().is_ok());
     }
 }
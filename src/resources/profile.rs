@@ -0,0 +1,236 @@
+use crate::{
+    constants::PROFILE_FILE,
+    data_include::config_file_path,
+    entities::SpawnableType,
+    resources::{PhaseManagerResource, RunSummaryResource, ScoreResource},
+};
+use amethyst::config::Config;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+// totals over every finished run
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct LifetimeStats {
+    pub runs_played: usize,
+    pub enemies_destroyed: usize,
+    pub bosses_defeated: usize,
+    pub items_purchased: usize,
+    pub best_score: usize,
+    pub furthest_phase: usize,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum UnlockRequirement {
+    RunsPlayed(usize),
+    EnemiesDestroyed(usize),
+    BossesDefeated(usize),
+    ItemsPurchased(usize),
+    Score(usize),        // best score of a single run
+    PhaseReached(usize), // furthest phase index reached in a single run
+}
+
+impl UnlockRequirement {
+    pub fn is_met(&self, stats: &LifetimeStats) -> bool {
+        match self {
+            UnlockRequirement::RunsPlayed(count) => stats.runs_played >= *count,
+            UnlockRequirement::EnemiesDestroyed(count) => stats.enemies_destroyed >= *count,
+            UnlockRequirement::BossesDefeated(count) => stats.bosses_defeated >= *count,
+            UnlockRequirement::ItemsPurchased(count) => stats.items_purchased >= *count,
+            UnlockRequirement::Score(score) => stats.best_score >= *score,
+            UnlockRequirement::PhaseReached(phase) => stats.furthest_phase >= *phase,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum UnlockReward {
+    StoreStock(SpawnableType, f32), // added to the store's stock probabilities
+    Character(String),              // name of a character in players.ron
+    StartingLoadout(Vec<SpawnableType>), // items and consumables given at the start of every run
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Unlock {
+    pub name: String,
+    pub description: String,
+    pub requirement: UnlockRequirement,
+    pub reward: UnlockReward,
+}
+
+// unlock rules, read from unlocks.ron
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct UnlocksResource {
+    pub unlocks: Vec<Unlock>,
+}
+
+impl UnlocksResource {
+    // characters are available unless an unlock that hasn't been earned rewards them
+    pub fn character_unlocked(&self, character: &str, profile: &ProfileResource) -> bool {
+        self.unlocks.iter().all(|unlock| match &unlock.reward {
+            UnlockReward::Character(name) if name == character => {
+                profile.unlocked.contains(&unlock.name)
+            }
+            _ => true,
+        })
+    }
+
+    // rewards of the unlocks the profile has earned
+    pub fn rewards<'a>(
+        &'a self,
+        profile: &'a ProfileResource,
+    ) -> impl Iterator<Item = &'a UnlockReward> {
+        self.unlocks
+            .iter()
+            .filter(move |unlock| profile.unlocked.contains(&unlock.name))
+            .map(|unlock| &unlock.reward)
+    }
+}
+
+// progress kept between runs
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct ProfileResource {
+    pub stats: LifetimeStats,
+    pub unlocked: Vec<String>, // names of earned unlocks
+}
+
+impl ProfileResource {
+    // loads the profile from the config directory, a missing or unreadable file starts a new
+    // profile and unreadable files are kept next to it so they aren't overwritten
+    pub fn load() -> Self {
+        let path = config_file_path(PROFILE_FILE);
+
+        if !path.is_file() {
+            return ProfileResource::default();
+        }
+
+        match <ProfileResource as Config>::load(&path) {
+            Ok(profile) => profile,
+            Err(error) => {
+                log::warn!("unable to read profile, starting a new profile: {}", error);
+
+                if fs::rename(&path, path.with_extension("ron.corrupt")).is_err() {
+                    log::warn!("unable to back up unreadable profile file");
+                }
+
+                ProfileResource::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = self.write(config_file_path(PROFILE_FILE)) {
+            log::error!("unable to save profile: {}", error);
+        }
+    }
+
+    // adds a finished run to the lifetime stats
+    pub fn record_run(
+        &mut self,
+        run_summary: &RunSummaryResource,
+        phase_manager: &PhaseManagerResource,
+        score: &ScoreResource,
+    ) {
+        self.stats.runs_played += 1;
+        self.stats.enemies_destroyed += run_summary.enemies_destroyed;
+        self.stats.bosses_defeated += run_summary.bosses_defeated;
+        self.stats.items_purchased += run_summary.items_purchased;
+        self.stats.best_score = self.stats.best_score.max(score.score);
+        self.stats.furthest_phase = self.stats.furthest_phase.max(phase_manager.phase_idx);
+    }
+
+    // earns every unlock whose requirement is met, returning the newly earned unlocks
+    pub fn update_unlocks<'a>(&mut self, unlocks: &'a UnlocksResource) -> Vec<&'a Unlock> {
+        let mut newly_unlocked = vec![];
+
+        for unlock in unlocks.unlocks.iter() {
+            if !self.unlocked.contains(&unlock.name) && unlock.requirement.is_met(&self.stats) {
+                self.unlocked.push(unlock.name.clone());
+                newly_unlocked.push(unlock);
+            }
+        }
+
+        newly_unlocked
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unlock_requirements() {
+        let stats = LifetimeStats {
+            runs_played: 5,
+            enemies_destroyed: 500,
+            bosses_defeated: 1,
+            items_purchased: 10,
+            best_score: 20000,
+            furthest_phase: 3,
+        };
+
+        assert!(UnlockRequirement::RunsPlayed(5).is_met(&stats));
+        assert!(!UnlockRequirement::RunsPlayed(6).is_met(&stats));
+        assert!(UnlockRequirement::EnemiesDestroyed(500).is_met(&stats));
+        assert!(!UnlockRequirement::EnemiesDestroyed(501).is_met(&stats));
+        assert!(UnlockRequirement::BossesDefeated(1).is_met(&stats));
+        assert!(!UnlockRequirement::BossesDefeated(2).is_met(&stats));
+        assert!(UnlockRequirement::ItemsPurchased(10).is_met(&stats));
+        assert!(!UnlockRequirement::ItemsPurchased(11).is_met(&stats));
+        assert!(UnlockRequirement::Score(20000).is_met(&stats));
+        assert!(!UnlockRequirement::Score(20001).is_met(&stats));
+        assert!(UnlockRequirement::PhaseReached(3).is_met(&stats));
+        assert!(!UnlockRequirement::PhaseReached(4).is_met(&stats));
+    }
+
+    #[test]
+    fn test_update_unlocks_only_returns_new_unlocks() {
+        let unlocks = UnlocksResource {
+            unlocks: vec![
+                Unlock {
+                    name: "veteran".to_string(),
+                    description: String::new(),
+                    requirement: UnlockRequirement::RunsPlayed(1),
+                    reward: UnlockReward::StartingLoadout(vec![]),
+                },
+                Unlock {
+                    name: "interceptor".to_string(),
+                    description: String::new(),
+                    requirement: UnlockRequirement::EnemiesDestroyed(500),
+                    reward: UnlockReward::Character("interceptor".to_string()),
+                },
+            ],
+        };
+        let mut profile = ProfileResource::default();
+
+        assert!(profile.update_unlocks(&unlocks).is_empty());
+
+        profile.stats.runs_played = 1;
+        let newly_unlocked = profile.update_unlocks(&unlocks);
+        assert_eq!(newly_unlocked.len(), 1);
+        assert_eq!(newly_unlocked[0].name, "veteran");
+
+        assert!(profile.update_unlocks(&unlocks).is_empty());
+        assert_eq!(profile.unlocked, vec!["veteran".to_string()]);
+        assert_eq!(unlocks.rewards(&profile).count(), 1);
+    }
+
+    #[test]
+    fn test_characters_locked_until_unlocked() {
+        let unlocks = UnlocksResource {
+            unlocks: vec![Unlock {
+                name: "interceptor".to_string(),
+                description: String::new(),
+                requirement: UnlockRequirement::EnemiesDestroyed(500),
+                reward: UnlockReward::Character("interceptor".to_string()),
+            }],
+        };
+        let mut profile = ProfileResource::default();
+
+        assert!(unlocks.character_unlocked("juggernaut", &profile));
+        assert!(!unlocks.character_unlocked("interceptor", &profile));
+
+        profile.stats.enemies_destroyed = 500;
+        profile.update_unlocks(&unlocks);
+        assert!(unlocks.character_unlocked("interceptor", &profile));
+    }
+}
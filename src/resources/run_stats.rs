@@ -0,0 +1,157 @@
+use crate::{
+    components::DamageKind,
+    constants::RUN_STATS_DIRECTORY,
+    data_include::config_file_path,
+    entities::{EnemyType, SpawnableType},
+    resources::RunSummaryResource,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Debug, fs, io, path::PathBuf};
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PurchaseRecord {
+    pub spawnable_type: SpawnableType,
+    pub phase_idx: usize,
+    pub loop_count: usize,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PhaseTimeRecord {
+    pub phase_idx: usize,
+    pub loop_count: usize,
+    pub time: f32, // seconds spent in the phase
+}
+
+// detailed record of a run for balancing, exported when the run ends
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct RunStatsResource {
+    pub kills: HashMap<EnemyType, usize>,
+    pub damage_dealt: HashMap<DamageKind, f32>, // to enemies
+    pub damage_taken: HashMap<DamageKind, f32>, // by the spaceship
+    pub defense_damage_taken: HashMap<DamageKind, f32>,
+    pub blasts_fired: usize, // by the spaceship
+    pub blasts_hit: usize,   // spaceship blasts that hit at least one enemy
+    pub crits: usize,        // critical hits on enemies
+    pub poisons: usize,      // enemies poisoned by blasts
+    pub money_earned: usize,
+    pub money_spent: usize,
+    pub purchases: Vec<PurchaseRecord>,
+    pub barrel_rolls: usize,
+    pub phase_times: Vec<PhaseTimeRecord>,
+}
+
+impl RunStatsResource {
+    // fraction of fired blasts that hit an enemy
+    pub fn accuracy(&self) -> f32 {
+        if self.blasts_fired == 0 {
+            0.0
+        } else {
+            self.blasts_hit as f32 / self.blasts_fired as f32
+        }
+    }
+
+    // adds time to the phase being played, starting a new record when the phase changes
+    pub fn add_phase_time(&mut self, phase_idx: usize, loop_count: usize, dt: f32) {
+        match self.phase_times.last_mut() {
+            Some(record) if record.phase_idx == phase_idx && record.loop_count == loop_count => {
+                record.time += dt;
+            }
+            _ => self.phase_times.push(PhaseTimeRecord {
+                phase_idx,
+                loop_count,
+                time: dt,
+            }),
+        }
+    }
+
+    // writes the stats as json and csv files named after the run
+    pub fn export(&self, run_summary: &RunSummaryResource, timestamp: u64) {
+        if let Err(error) = self.write_exports(run_summary, timestamp) {
+            log::error!("unable to export run stats: {}", error);
+        }
+    }
+
+    fn write_exports(&self, run_summary: &RunSummaryResource, timestamp: u64) -> io::Result<()> {
+        let directory = config_file_path(RUN_STATS_DIRECTORY);
+        fs::create_dir_all(&directory)?;
+
+        let file_name = format!("run_{}_{}", timestamp, run_summary.run_id);
+
+        let json = serde_json::to_string_pretty(&RunStatsExport {
+            run_summary,
+            accuracy: self.accuracy(),
+            stats: self,
+        })
+        .map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
+        fs::write(export_path(&directory, &file_name, "json"), json)?;
+
+        fs::write(
+            export_path(&directory, &file_name, "csv"),
+            self.to_csv(run_summary),
+        )
+    }
+
+    // one `stat,key,value` row per recorded value
+    fn to_csv(&self, run_summary: &RunSummaryResource) -> String {
+        let mut rows = vec!["stat,key,value".to_string()];
+
+        rows.push(format!("run,character,{}", run_summary.character));
+        rows.push(format!("run,difficulty,{:?}", run_summary.difficulty));
+        rows.push(format!("run,run_id,{}", run_summary.run_id));
+        rows.push(format!("run,endless_mode,{}", run_summary.endless_mode));
+        push_map_rows(&mut rows, "kills", &self.kills);
+        push_map_rows(&mut rows, "damage_dealt", &self.damage_dealt);
+        push_map_rows(&mut rows, "damage_taken", &self.damage_taken);
+        push_map_rows(
+            &mut rows,
+            "defense_damage_taken",
+            &self.defense_damage_taken,
+        );
+        rows.push(format!("blasts,fired,{}", self.blasts_fired));
+        rows.push(format!("blasts,hit,{}", self.blasts_hit));
+        rows.push(format!("blasts,accuracy,{}", self.accuracy()));
+        rows.push(format!("procs,crit,{}", self.crits));
+        rows.push(format!("procs,poison,{}", self.poisons));
+        rows.push(format!("money,earned,{}", self.money_earned));
+        rows.push(format!("money,spent,{}", self.money_spent));
+        rows.push(format!("barrel_rolls,total,{}", self.barrel_rolls));
+
+        for purchase in self.purchases.iter() {
+            rows.push(format!(
+                "purchase,{:?},loop {} phase {}",
+                purchase.spawnable_type, purchase.loop_count, purchase.phase_idx
+            ));
+        }
+
+        for record in self.phase_times.iter() {
+            rows.push(format!(
+                "phase_time,loop {} phase {},{}",
+                record.loop_count, record.phase_idx, record.time
+            ));
+        }
+
+        rows.join("\n") + "\n"
+    }
+}
+
+#[derive(Serialize)]
+struct RunStatsExport<'a> {
+    run_summary: &'a RunSummaryResource,
+    accuracy: f32,
+    stats: &'a RunStatsResource,
+}
+
+fn export_path(directory: &PathBuf, file_name: &str, extension: &str) -> PathBuf {
+    directory.join(file_name).with_extension(extension)
+}
+
+fn push_map_rows<K: Debug, V: Debug>(rows: &mut Vec<String>, stat: &str, map: &HashMap<K, V>) {
+    let mut map_rows: Vec<String> = map
+        .iter()
+        .map(|(key, value)| format!("{},{:?},{:?}", stat, key, value))
+        .collect();
+
+    // hash maps don't have a stable order
+    map_rows.sort();
+    rows.extend(map_rows);
+}
@@ -0,0 +1,25 @@
+use crate::resources::DifficultyType;
+use serde::{Deserialize, Serialize};
+
+// choices made at the start of a run and what happened during it
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct RunSummaryResource {
+    pub character: String,
+    pub difficulty: DifficultyType,
+    // random id drawn at the start of the run, it only tells runs apart in the high score table and
+    // stat exports and doesn't seed anything
+    #[serde(alias = "seed")]
+    pub run_id: u64,
+    #[serde(default)]
+    pub endless_mode: bool, // the phase map loops after the last boss
+    #[serde(default)]
+    pub enemies_destroyed: usize,
+    #[serde(default)]
+    pub bosses_defeated: usize,
+    #[serde(default)]
+    pub items_purchased: usize,
+    #[serde(default)]
+    pub player_damaged: bool,
+    #[serde(default)]
+    pub defense_breached: bool, // an enemy reached the bottom of the arena
+}
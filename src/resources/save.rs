@@ -0,0 +1,599 @@
+use crate::{
+    components::{
+        AnimationComponent, AutoFireComponent, BarrelRollAbilityComponent, BlastComponent,
+        BlastType, BlasterComponent, ColorChannelChange, ConsumableComponent, DefenseTag,
+        DifficultyScaledTag, EffectTag, EnemyComponent, FadeComponent, HealthComponent,
+        Hitbox2DComponent, InventoryComponent, ItemComponent, ManualFireComponent,
+        Motion2DComponent, PathFollowerComponent, PlayerComponent, PoisonComponent,
+        PowerUpsComponent, RepeaterComponent, SecondaryFireComponent, TimeLimitComponent,
+    },
+    constants::SAVE_FILE,
+    data_include::config_file_path,
+    entities::{spawn_blasts, spawn_enemy, spawn_spawnable, EnemyType, SpawnableType},
+    resources::{
+        ConsumablesResource, EffectsResource, EnemiesResource, FormationsResource,
+        GameParametersResource, ItemsResource, PhaseManagerResource, RunStatsResource,
+        RunSummaryResource, ScoreResource, SpriteSheetsResource, StoreResource,
+    },
+};
+use amethyst::{
+    config::Config,
+    core::{math::Vector3, transform::Transform},
+    ecs::prelude::{Builder, Entities, Entity, Join, LazyUpdate, ReadExpect, World, WorldExt},
+    renderer::{palette::Srgba, resources::Tint, SpriteRender, Transparent},
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SavedPlayer {
+    pub player_component: PlayerComponent,
+    pub health_component: HealthComponent,
+    pub motion2d_component: Motion2DComponent,
+    pub blaster_component: Option<BlasterComponent>,
+    pub manual_fire_component: Option<ManualFireComponent>,
+    pub secondary_fire_component: Option<SecondaryFireComponent>,
+    pub barrel_roll_ability_component: Option<BarrelRollAbilityComponent>,
+    #[serde(default)]
+    pub inventory_component: Option<InventoryComponent>,
+    #[serde(default)]
+    pub power_ups_component: Option<PowerUpsComponent>,
+    pub position: Vector3<f32>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SavedEnemy {
+    pub enemy_component: EnemyComponent,
+    pub health_component: HealthComponent,
+    pub motion2d_component: Motion2DComponent,
+    pub blaster_component: Option<BlasterComponent>,
+    pub autofire_component: Option<AutoFireComponent>,
+    #[serde(default)]
+    pub poison_component: Option<PoisonComponent>,
+    #[serde(default)]
+    pub path_follower_component: Option<PathFollowerComponent>,
+    pub difficulty_scaled: bool,
+    pub position: Vector3<f32>,
+    #[serde(default)]
+    pub rotation: f32, // radians around the z axis
+}
+
+// blasts in flight, the entities a piercing blast has already hit aren't kept
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SavedBlast {
+    pub damage: f32,
+    pub poison_damage: f32,
+    pub blast_type: BlastType,
+    pub pierce: usize,
+    pub bounce: usize,
+    pub homing: f32,
+    pub split: usize,
+    pub sprite_number: usize,
+    pub hitbox_component: Hitbox2DComponent,
+    pub motion2d_component: Motion2DComponent,
+    pub position: Vector3<f32>,
+    pub scale: Vector3<f32>,
+}
+
+// explosions, giblets and other entities spawned from effect data
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SavedEffect {
+    pub spritesheet: String,
+    pub sprite_number: usize,
+    pub animation_component: Option<AnimationComponent>,
+    pub time_limit_component: Option<TimeLimitComponent>,
+    pub motion2d_component: Option<Motion2DComponent>,
+    pub fade_component: Option<FadeComponent>,
+    pub position: Vector3<f32>,
+    pub rotation: f32,
+    pub scale: Vector3<f32>,
+}
+
+// consumables and items waiting to be picked up
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SavedPickup {
+    pub spawnable_type: SpawnableType,
+    pub position: Vector3<f32>,
+}
+
+// snapshot of a run in progress
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SaveGame {
+    pub run_summary: RunSummaryResource,
+    pub phase_manager: PhaseManagerResource,
+    pub store: StoreResource,
+    pub formations: FormationsResource,
+    pub score: ScoreResource,
+    #[serde(default)]
+    pub run_stats: RunStatsResource,
+    pub player: Option<SavedPlayer>,
+    pub defense_health: Option<HealthComponent>,
+    pub enemies: Vec<SavedEnemy>,
+    pub pickups: Vec<SavedPickup>,
+    #[serde(default)]
+    pub blasts: Vec<SavedBlast>,
+    #[serde(default)]
+    pub effects: Vec<SavedEffect>,
+}
+
+impl SaveGame {
+    // snapshots the run in the world
+    pub fn capture(world: &World) -> Self {
+        let entities = world.entities();
+        let transforms = world.read_storage::<Transform>();
+        let healths = world.read_storage::<HealthComponent>();
+        let motion_2ds = world.read_storage::<Motion2DComponent>();
+        let blasters = world.read_storage::<BlasterComponent>();
+
+        let player = {
+            let players = world.read_storage::<PlayerComponent>();
+            let manual_fires = world.read_storage::<ManualFireComponent>();
+            let secondary_fires = world.read_storage::<SecondaryFireComponent>();
+            let barrel_roll_abilities = world.read_storage::<BarrelRollAbilityComponent>();
+            let inventories = world.read_storage::<InventoryComponent>();
+            let power_ups = world.read_storage::<PowerUpsComponent>();
+
+            (&entities, &players, &healths, &motion_2ds, &transforms)
+                .join()
+                .next()
+                .map(
+                    |(entity, player, health, motion_2d, transform)| SavedPlayer {
+                        player_component: player.clone(),
+                        health_component: health.clone(),
+                        motion2d_component: motion_2d.clone(),
+                        blaster_component: blasters.get(entity).cloned(),
+                        manual_fire_component: manual_fires.get(entity).cloned(),
+                        secondary_fire_component: secondary_fires.get(entity).cloned(),
+                        barrel_roll_ability_component: barrel_roll_abilities.get(entity).cloned(),
+                        inventory_component: inventories.get(entity).cloned(),
+                        power_ups_component: power_ups.get(entity).cloned(),
+                        position: *transform.translation(),
+                    },
+                )
+        };
+
+        let defense_health = (&world.read_storage::<DefenseTag>(), &healths)
+            .join()
+            .next()
+            .map(|(_, health)| health.clone());
+
+        let mut enemies = vec![];
+        {
+            let enemy_components = world.read_storage::<EnemyComponent>();
+            let autofires = world.read_storage::<AutoFireComponent>();
+            let poisons = world.read_storage::<PoisonComponent>();
+            let path_followers = world.read_storage::<PathFollowerComponent>();
+            let difficulty_scaled_tags = world.read_storage::<DifficultyScaledTag>();
+
+            for (entity, enemy, health, motion_2d, transform) in (
+                &entities,
+                &enemy_components,
+                &healths,
+                &motion_2ds,
+                &transforms,
+            )
+                .join()
+            {
+                enemies.push(SavedEnemy {
+                    enemy_component: enemy.clone(),
+                    health_component: health.clone(),
+                    motion2d_component: motion_2d.clone(),
+                    blaster_component: blasters.get(entity).cloned(),
+                    autofire_component: autofires.get(entity).cloned(),
+                    poison_component: poisons.get(entity).cloned(),
+                    path_follower_component: path_followers.get(entity).cloned(),
+                    difficulty_scaled: difficulty_scaled_tags.get(entity).is_some(),
+                    position: *transform.translation(),
+                    rotation: transform.euler_angles().2,
+                });
+            }
+        }
+
+        let mut pickups = vec![];
+        for (consumable, transform) in
+            (&world.read_storage::<ConsumableComponent>(), &transforms).join()
+        {
+            pickups.push(SavedPickup {
+                spawnable_type: consumable.spawnable_type.clone(),
+                position: *transform.translation(),
+            });
+        }
+        for (item, transform) in (&world.read_storage::<ItemComponent>(), &transforms).join() {
+            pickups.push(SavedPickup {
+                spawnable_type: item.spawnable_type.clone(),
+                position: *transform.translation(),
+            });
+        }
+
+        let mut blasts = vec![];
+        {
+            let hitboxes = world.read_storage::<Hitbox2DComponent>();
+            let sprite_renders = world.read_storage::<SpriteRender>();
+
+            for (blast, hitbox, motion_2d, sprite_render, transform) in (
+                &world.read_storage::<BlastComponent>(),
+                &hitboxes,
+                &motion_2ds,
+                &sprite_renders,
+                &transforms,
+            )
+                .join()
+            {
+                blasts.push(SavedBlast {
+                    damage: blast.damage,
+                    poison_damage: blast.poison_damage,
+                    blast_type: blast.blast_type.clone(),
+                    pierce: blast.pierce,
+                    bounce: blast.bounce,
+                    homing: blast.homing,
+                    split: blast.split,
+                    sprite_number: sprite_render.sprite_number,
+                    hitbox_component: hitbox.clone(),
+                    motion2d_component: motion_2d.clone(),
+                    position: *transform.translation(),
+                    scale: *transform.scale(),
+                });
+            }
+        }
+
+        let mut effects = vec![];
+        {
+            let spritesheets_resource = world.read_resource::<SpriteSheetsResource>();
+            let animations = world.read_storage::<AnimationComponent>();
+            let time_limits = world.read_storage::<TimeLimitComponent>();
+            let fades = world.read_storage::<FadeComponent>();
+
+            for (entity, _, sprite_render, transform) in (
+                &entities,
+                &world.read_storage::<EffectTag>(),
+                &world.read_storage::<SpriteRender>(),
+                &transforms,
+            )
+                .join()
+            {
+                let spritesheet = spritesheets_resource
+                    .spritesheets
+                    .iter()
+                    .find(|(_, handle)| **handle == sprite_render.sprite_sheet)
+                    .map(|(name, _)| name.clone());
+
+                if let Some(spritesheet) = spritesheet {
+                    effects.push(SavedEffect {
+                        spritesheet,
+                        sprite_number: sprite_render.sprite_number,
+                        animation_component: animations.get(entity).cloned(),
+                        time_limit_component: time_limits.get(entity).cloned(),
+                        motion2d_component: motion_2ds.get(entity).cloned(),
+                        fade_component: fades.get(entity).cloned(),
+                        position: *transform.translation(),
+                        rotation: transform.euler_angles().2,
+                        scale: *transform.scale(),
+                    });
+                }
+            }
+        }
+
+        SaveGame {
+            run_summary: (*world.read_resource::<RunSummaryResource>()).clone(),
+            phase_manager: (*world.read_resource::<PhaseManagerResource>()).clone(),
+            store: (*world.read_resource::<StoreResource>()).clone(),
+            formations: (*world.read_resource::<FormationsResource>()).clone(),
+            score: (*world.read_resource::<ScoreResource>()).clone(),
+            run_stats: (*world.read_resource::<RunStatsResource>()).clone(),
+            player,
+            defense_health,
+            enemies,
+            pickups,
+            blasts,
+            effects,
+        }
+    }
+
+    // restores the run into a world that has just been initialized for a new run
+    pub fn restore(self, world: &mut World) {
+        world.write_resource::<GameParametersResource>().difficulty =
+            self.run_summary.difficulty.clone();
+        world.insert(self.run_summary);
+        world.insert(self.phase_manager);
+        world.insert(self.store);
+        world.insert(self.formations);
+        world.insert(self.score);
+        world.insert(self.run_stats);
+
+        if let Some(saved_player) = self.player {
+            let player_entity = (&world.entities(), &world.read_storage::<PlayerComponent>())
+                .join()
+                .next()
+                .map(|(entity, _)| entity);
+
+            if let Some(player_entity) = player_entity {
+                insert_component(world, player_entity, saved_player.player_component);
+                insert_component(world, player_entity, saved_player.health_component);
+                insert_component(world, player_entity, saved_player.motion2d_component);
+                if let Some(blaster) = saved_player.blaster_component {
+                    insert_component(world, player_entity, blaster);
+                }
+                if let Some(manual_fire) = saved_player.manual_fire_component {
+                    insert_component(world, player_entity, manual_fire);
+                }
+                if let Some(secondary_fire) = saved_player.secondary_fire_component {
+                    insert_component(world, player_entity, secondary_fire);
+                }
+                if let Some(barrel_roll_ability) = saved_player.barrel_roll_ability_component {
+                    insert_component(world, player_entity, barrel_roll_ability);
+                }
+                if let Some(inventory) = saved_player.inventory_component {
+                    insert_component(world, player_entity, inventory);
+                }
+                if let Some(power_ups) = saved_player.power_ups_component {
+                    insert_component(world, player_entity, power_ups);
+                }
+                if let Some(transform) = world.write_storage::<Transform>().get_mut(player_entity) {
+                    transform.set_translation(saved_player.position);
+                }
+            }
+        }
+
+        if let Some(defense_health) = self.defense_health {
+            let defense_entity = (&world.entities(), &world.read_storage::<DefenseTag>())
+                .join()
+                .next()
+                .map(|(entity, _)| entity);
+
+            if let Some(defense_entity) = defense_entity {
+                insert_component(world, defense_entity, defense_health);
+            }
+        }
+
+        {
+            let (
+                entities,
+                lazy_update,
+                consumables_resource,
+                enemies_resource,
+                items_resource,
+                effects_resource,
+                spritesheets_resource,
+            ) = world.system_data::<(
+                Entities,
+                ReadExpect<LazyUpdate>,
+                ReadExpect<ConsumablesResource>,
+                ReadExpect<EnemiesResource>,
+                ReadExpect<ItemsResource>,
+                ReadExpect<EffectsResource>,
+                ReadExpect<SpriteSheetsResource>,
+            )>();
+
+            let mut boss_body = None;
+            let mut boss_head = None;
+            let mut boss_right_shoulder = None;
+            let mut boss_left_shoulder = None;
+
+            for saved_enemy in self.enemies {
+                if let SpawnableType::Enemy(enemy_type) =
+                    &saved_enemy.enemy_component.spawnable_type
+                {
+                    let mut enemy_transform = spawn_transform(saved_enemy.position);
+                    enemy_transform.set_rotation_2d(saved_enemy.rotation);
+
+                    let enemy_entity = spawn_enemy(
+                        enemy_type,
+                        enemy_transform,
+                        &enemies_resource,
+                        &spritesheets_resource,
+                        &entities,
+                        &lazy_update,
+                    );
+
+                    match enemy_type {
+                        EnemyType::RepeaterBody => boss_body = Some(enemy_entity),
+                        EnemyType::RepeaterHead => boss_head = Some(enemy_entity),
+                        EnemyType::RepeaterRightShoulder => {
+                            boss_right_shoulder = Some(enemy_entity)
+                        }
+                        EnemyType::RepeaterLeftShoulder => boss_left_shoulder = Some(enemy_entity),
+                        _ => {}
+                    }
+
+                    // replace the freshly spawned components with the saved ones
+                    lazy_update.insert(enemy_entity, saved_enemy.enemy_component);
+                    lazy_update.insert(enemy_entity, saved_enemy.health_component);
+                    lazy_update.insert(enemy_entity, saved_enemy.motion2d_component);
+                    if let Some(blaster) = saved_enemy.blaster_component {
+                        lazy_update.insert(enemy_entity, blaster);
+                    }
+                    if let Some(autofire) = saved_enemy.autofire_component {
+                        lazy_update.insert(enemy_entity, autofire);
+                    }
+                    if let Some(poison) = saved_enemy.poison_component {
+                        lazy_update.insert(enemy_entity, poison);
+                    }
+                    if let Some(path_follower) = saved_enemy.path_follower_component {
+                        lazy_update.insert(enemy_entity, path_follower);
+                    }
+                    if saved_enemy.difficulty_scaled {
+                        lazy_update.insert(enemy_entity, DifficultyScaledTag);
+                    }
+                }
+            }
+
+            // the boss is defeated once its head and body are destroyed, so it only needs to be
+            // tracked again while one of them is still alive
+            if boss_body.is_some() || boss_head.is_some() {
+                let repeater = RepeaterComponent {
+                    body: boss_body.unwrap_or_else(|| destroyed_entity(&entities)),
+                    head: boss_head.unwrap_or_else(|| destroyed_entity(&entities)),
+                    right_shoulder: boss_right_shoulder
+                        .unwrap_or_else(|| destroyed_entity(&entities)),
+                    left_shoulder: boss_left_shoulder
+                        .unwrap_or_else(|| destroyed_entity(&entities)),
+                };
+
+                lazy_update.create_entity(&entities).with(repeater).build();
+            }
+
+            for saved_blast in self.blasts {
+                let blast_sprite_render = SpriteRender {
+                    sprite_sheet: spritesheets_resource.spritesheets["blasts"].clone(),
+                    sprite_number: saved_blast.sprite_number,
+                };
+
+                let blast_component = BlastComponent {
+                    damage: saved_blast.damage,
+                    poison_damage: saved_blast.poison_damage,
+                    blast_type: saved_blast.blast_type,
+                    pierce: saved_blast.pierce,
+                    bounce: saved_blast.bounce,
+                    homing: saved_blast.homing,
+                    split: saved_blast.split,
+                    hit_entities: vec![],
+                };
+
+                let mut blast_transform = spawn_transform(saved_blast.position);
+                blast_transform.set_scale(saved_blast.scale);
+
+                spawn_blasts(
+                    1,
+                    0.0,
+                    blast_sprite_render,
+                    blast_component,
+                    saved_blast.hitbox_component,
+                    saved_blast.motion2d_component,
+                    blast_transform,
+                    &entities,
+                    &lazy_update,
+                );
+            }
+
+            for saved_effect in self.effects {
+                let spritesheet = match spritesheets_resource
+                    .spritesheets
+                    .get(&saved_effect.spritesheet)
+                {
+                    Some(spritesheet) => spritesheet.clone(),
+                    None => continue,
+                };
+
+                let mut effect_transform = spawn_transform(saved_effect.position);
+                effect_transform.set_rotation_2d(saved_effect.rotation);
+                effect_transform.set_scale(saved_effect.scale);
+
+                let effect_entity = lazy_update
+                    .create_entity(&entities)
+                    .with(SpriteRender {
+                        sprite_sheet: spritesheet,
+                        sprite_number: saved_effect.sprite_number,
+                    })
+                    .with(effect_transform)
+                    .with(Transparent)
+                    .with(EffectTag)
+                    .build();
+
+                if let Some(animation) = saved_effect.animation_component {
+                    lazy_update.insert(effect_entity, animation);
+                }
+                if let Some(time_limit) = saved_effect.time_limit_component {
+                    lazy_update.insert(effect_entity, time_limit);
+                }
+                if let Some(motion_2d) = saved_effect.motion2d_component {
+                    lazy_update.insert(effect_entity, motion_2d);
+                }
+                if let Some(fade) = saved_effect.fade_component {
+                    lazy_update.insert(effect_entity, fade_tint(&fade));
+                    lazy_update.insert(effect_entity, fade);
+                }
+            }
+
+            for saved_pickup in self.pickups {
+                spawn_spawnable(
+                    &saved_pickup.spawnable_type,
+                    spawn_transform(saved_pickup.position),
+                    &consumables_resource,
+                    &enemies_resource,
+                    &items_resource,
+                    &effects_resource,
+                    &spritesheets_resource,
+                    &entities,
+                    &lazy_update,
+                );
+            }
+        }
+
+        world.maintain();
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = self.write(config_file_path(SAVE_FILE)) {
+            log::error!("unable to save run: {}", error);
+        }
+    }
+
+    pub fn exists() -> bool {
+        config_file_path(SAVE_FILE).is_file()
+    }
+
+    // loads the saved run and removes the save file so it is only resumed once
+    pub fn take() -> Option<Self> {
+        let path = config_file_path(SAVE_FILE);
+
+        if !path.is_file() {
+            return None;
+        }
+
+        match <SaveGame as Config>::load(&path) {
+            Ok(save_game) => {
+                if fs::remove_file(&path).is_err() {
+                    log::warn!("unable to remove save file");
+                }
+
+                Some(save_game)
+            }
+            Err(error) => {
+                log::warn!("unable to read saved run, starting a new run: {}", error);
+
+                if fs::rename(&path, path.with_extension("ron.corrupt")).is_err() {
+                    log::warn!("unable to back up unreadable save file");
+                }
+
+                None
+            }
+        }
+    }
+}
+
+// stands in for boss parts that were destroyed before the run was saved
+fn destroyed_entity(entities: &Entities) -> Entity {
+    let entity = entities.create();
+    entities.delete(entity).expect("unable to delete entity");
+    entity
+}
+
+// tint matching the current values of a fade, as set when the effect was spawned
+fn fade_tint(fade: &FadeComponent) -> Tint {
+    let channel_value =
+        |change: &Option<ColorChannelChange>| change.as_ref().map_or(1.0, |change| change.value);
+
+    Tint(Srgba::new(
+        channel_value(&fade.red_change),
+        channel_value(&fade.green_change),
+        channel_value(&fade.blue_change),
+        channel_value(&fade.alpha_change),
+    ))
+}
+
+fn spawn_transform(position: Vector3<f32>) -> Transform {
+    let mut transform = Transform::default();
+    transform.set_translation(position);
+    transform
+}
+
+fn insert_component<T: amethyst::ecs::Component>(
+    world: &mut World,
+    entity: amethyst::ecs::Entity,
+    component: T,
+) {
+    world
+        .write_storage::<T>()
+        .insert(entity, component)
+        .expect("unable to insert saved component");
+}
@@ -0,0 +1,85 @@
+use crate::constants::{COMBO_MAX_MULTIPLIER, COMBO_MULTIPLIER_STEP, COMBO_WINDOW};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct ScoreResource {
+    pub score: usize,
+    pub combo: usize,     // kills in the current combo
+    pub combo_timer: f32, // time left to continue the combo
+    pub max_combo: usize,
+}
+
+impl ScoreResource {
+    pub fn multiplier(&self) -> f32 {
+        (1.0 + self.combo.saturating_sub(1) as f32 * COMBO_MULTIPLIER_STEP)
+            .min(COMBO_MAX_MULTIPLIER)
+    }
+
+    // adds a kill to the combo and awards its score with the combo multiplier
+    pub fn add_kill(&mut self, score_value: usize) {
+        self.combo += 1;
+        self.combo_timer = COMBO_WINDOW;
+        self.max_combo = self.max_combo.max(self.combo);
+        self.score += (score_value as f32 * self.multiplier()).round() as usize;
+    }
+
+    pub fn reset_combo(&mut self) {
+        self.combo = 0;
+        self.combo_timer = 0.0;
+    }
+
+    // counts down the combo timer, ending the combo when it runs out
+    pub fn update(&mut self, dt: f32) {
+        if self.combo_timer > 0.0 {
+            self.combo_timer -= dt;
+
+            if self.combo_timer <= 0.0 {
+                self.reset_combo();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_combo_increases_score_multiplier() {
+        let mut score = ScoreResource::default();
+
+        score.add_kill(100);
+        assert_eq!(score.score, 100);
+
+        score.add_kill(100);
+        let second_kill_score = (100.0 * (1.0 + COMBO_MULTIPLIER_STEP)).round() as usize;
+        assert_eq!(score.score, 100 + second_kill_score);
+        assert_eq!(score.combo, 2);
+        assert_eq!(score.max_combo, 2);
+    }
+
+    #[test]
+    fn test_combo_multiplier_is_capped() {
+        let score = ScoreResource {
+            combo: 10_000,
+            ..ScoreResource::default()
+        };
+
+        assert_eq!(score.multiplier(), COMBO_MAX_MULTIPLIER);
+    }
+
+    #[test]
+    fn test_combo_ends_when_timer_runs_out() {
+        let mut score = ScoreResource::default();
+        score.add_kill(100);
+        score.add_kill(100);
+
+        score.update(COMBO_WINDOW / 2.0);
+        assert_eq!(score.combo, 2);
+
+        score.update(COMBO_WINDOW);
+        assert_eq!(score.combo, 0);
+        assert_eq!(score.multiplier(), 1.0);
+        assert_eq!(score.max_combo, 2);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     components::PlayerComponent,
-    constants::{ARENA_MAX_Y, ITEM_SPAWN_Y_OFFSET},
+    constants::{ARENA_MAX_Y, ITEM_SPAWN_Y_OFFSET, STORE_MAX_SLOTS},
     entities::{spawn_consumable, spawn_item, SpawnableType},
     resources::{ConsumablesResource, ItemsResource, SpriteSheetsResource},
 };
@@ -18,16 +18,98 @@ pub struct StoreResource {
     pub stock_probs: StockProbabilities,
     pub restock_timer: f32,
     pub restock_period: f32,
+    #[serde(default = "des_slot_count")]
+    pub slot_count: usize,
+    #[serde(default)]
     pub inventory: Vec<Option<SpawnableType>>,
+    #[serde(default)]
+    pub locked: Vec<bool>, // locked slots keep their stock when the store restocks
+    #[serde(default)]
+    pub reroll_cost: usize,
+    #[serde(default)]
+    pub reroll_cost_increase: usize, // added to the reroll cost after each reroll
+    #[serde(default = "des_price_multiplier")]
+    pub price_multiplier: f32, // set from the difficulty at the start of a run
+}
+
+fn des_slot_count() -> usize {
+    3
+}
+
+fn des_price_multiplier() -> f32 {
+    1.0
 }
 
 impl StoreResource {
+    // price after the store's price multiplier
+    pub fn price(&self, base_price: usize) -> usize {
+        (base_price as f32 * self.price_multiplier).round() as usize
+    }
+
+    // checks that every slot can be bought, called when loading store.ron
+    pub fn validate(&self) -> Result<(), String> {
+        if self.slot_count == 0 || self.slot_count > STORE_MAX_SLOTS {
+            return Err(format!(
+                "slot_count is {}, expected 1 to {}",
+                self.slot_count, STORE_MAX_SLOTS
+            ));
+        }
+
+        Ok(())
+    }
+
+    // sizes the inventory and slot locks to the configured slot count
+    pub fn resize_slots(&mut self) {
+        self.inventory.resize(self.slot_count, None);
+        self.locked.resize(self.slot_count, false);
+    }
+
+    pub fn is_locked(&self, inventory_index: usize) -> bool {
+        self.locked.get(inventory_index).copied().unwrap_or(false)
+    }
+
+    // locks or unlocks a slot, empty slots can't be locked
+    pub fn toggle_lock(&mut self, inventory_index: usize) {
+        self.resize_slots();
+        if self.inventory[inventory_index].is_some() {
+            self.locked[inventory_index] = !self.locked[inventory_index];
+        }
+    }
+
+    // restocks the unlocked slots if the player can pay for it, each reroll costs more than the last
+    pub fn reroll(&mut self, player: &mut PlayerComponent) -> bool {
+        let cost = self.price(self.reroll_cost);
+        if player.money < cost {
+            return false;
+        }
+
+        player.money -= cost;
+        self.reroll_cost += self.reroll_cost_increase;
+        self.choose_stock();
+        true
+    }
+
     fn choose_stock(&mut self) {
-        self.inventory = vec![None, None, None];
+        self.resize_slots();
         let mut choose_pool = self.stock_probs.clone();
 
-        // choose 3 items
-        for i in 0..3 {
+        // stock in locked slots can't be chosen again
+        for i in 0..self.slot_count {
+            if self.locked[i] {
+                if let Some(locked_type) = &self.inventory[i] {
+                    choose_pool.retain(|(entity_type, _)| entity_type != locked_type);
+                }
+            } else {
+                self.inventory[i] = None;
+            }
+        }
+
+        // choose an item for each unlocked slot
+        for i in 0..self.slot_count {
+            if self.locked[i] {
+                continue;
+            }
+
             let total_probs = choose_pool.iter().fold(0.0, |sum, item| sum + item.1);
 
             // choose an item
@@ -68,12 +150,14 @@ impl StoreResource {
         sprite_resource: &ReadExpect<SpriteSheetsResource>,
         lazy_update: &ReadExpect<LazyUpdate>,
     ) -> bool {
+        self.resize_slots();
         if let Some(entity_type) = &self.inventory[inventory_index] {
             match entity_type {
                 SpawnableType::Item(item_type) => {
                     let item_data = items_resource.item_entities[item_type].clone();
-                    if player.money >= item_data.item_component.price {
-                        player.money -= item_data.item_component.price;
+                    let price = self.price(item_data.item_component.price);
+                    if player.money >= price {
+                        player.money -= price;
 
                         let mut spawn_transform = Transform::default();
                         spawn_transform.set_translation_xyz(
@@ -98,14 +182,16 @@ impl StoreResource {
                         }
 
                         self.inventory[inventory_index] = None; //change item slot data to None
+                        self.locked[inventory_index] = false;
                         return true;
                     }
                 }
                 SpawnableType::Consumable(consumable_type) => {
                     let consumable_data =
                         consumables_resource.consumable_entities[consumable_type].clone();
-                    if player.money >= consumable_data.consumable_component.price {
-                        player.money -= consumable_data.consumable_component.price;
+                    let price = self.price(consumable_data.consumable_component.price);
+                    if player.money >= price {
+                        player.money -= price;
 
                         let mut spawn_transform = Transform::default();
                         spawn_transform.set_translation_xyz(
@@ -124,6 +210,7 @@ impl StoreResource {
                         );
 
                         self.inventory[inventory_index] = None; //change item slot data to None
+                        self.locked[inventory_index] = false;
                         return true;
                     }
                 }
@@ -146,3 +233,144 @@ impl StoreResource {
         false
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::entities::{ConsumableType, ItemType};
+
+    #[test]
+    fn test_choose_stock_fills_slots_without_duplicates() {
+        let mut store = StoreResource {
+            stock_probs: vec![
+                (SpawnableType::Item(ItemType::Spice), 1.0),
+                (SpawnableType::Item(ItemType::SteelBarrel), 1.0),
+                (SpawnableType::Consumable(ConsumableType::Armor), 1.0),
+            ],
+            slot_count: 3,
+            ..StoreResource::default()
+        };
+
+        store.choose_stock();
+
+        for (spawnable_type, probability) in store.stock_probs.iter() {
+            assert!(store.inventory.contains(&Some(spawnable_type.clone())));
+
+            // items become less likely to be stocked again, consumables don't
+            match spawnable_type {
+                SpawnableType::Item(_) => assert_eq!(*probability, 0.5),
+                _ => assert_eq!(*probability, 1.0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_choose_stock_keeps_locked_slots() {
+        let locked_type = SpawnableType::Item(ItemType::Spice);
+        let mut store = StoreResource {
+            stock_probs: vec![
+                (locked_type.clone(), 1.0),
+                (SpawnableType::Item(ItemType::SteelBarrel), 1.0),
+                (SpawnableType::Item(ItemType::TungstenTips), 1.0),
+                (SpawnableType::Item(ItemType::SeekerChip), 1.0),
+            ],
+            slot_count: 3,
+            ..StoreResource::default()
+        };
+        store.resize_slots();
+        store.inventory[0] = Some(locked_type.clone());
+        store.toggle_lock(0);
+
+        for _ in 0..10 {
+            store.choose_stock();
+
+            assert_eq!(store.inventory[0], Some(locked_type.clone()));
+            assert!(store.inventory[1].is_some());
+            assert!(store.inventory[2].is_some());
+            assert_ne!(store.inventory[1], store.inventory[0]);
+            assert_ne!(store.inventory[2], store.inventory[0]);
+            assert_ne!(store.inventory[1], store.inventory[2]);
+        }
+    }
+
+    #[test]
+    fn test_toggle_lock() {
+        let mut store = StoreResource {
+            slot_count: 3,
+            ..StoreResource::default()
+        };
+        store.resize_slots();
+        store.inventory[1] = Some(SpawnableType::Item(ItemType::Spice));
+
+        // empty slots can't be locked
+        store.toggle_lock(0);
+        assert!(!store.is_locked(0));
+
+        store.toggle_lock(1);
+        assert!(store.is_locked(1));
+        store.toggle_lock(1);
+        assert!(!store.is_locked(1));
+    }
+
+    #[test]
+    fn test_reroll_charges_increasing_cost() {
+        let mut store = StoreResource {
+            stock_probs: vec![(SpawnableType::Item(ItemType::Spice), 1.0)],
+            slot_count: 1,
+            reroll_cost: 5,
+            reroll_cost_increase: 5,
+            price_multiplier: 1.0,
+            ..StoreResource::default()
+        };
+        let mut player = PlayerComponent {
+            money: 12,
+            ..PlayerComponent::default()
+        };
+
+        assert!(store.reroll(&mut player));
+        assert_eq!(player.money, 7);
+        assert_eq!(store.reroll_cost, 10);
+        assert_eq!(
+            store.inventory,
+            vec![Some(SpawnableType::Item(ItemType::Spice))]
+        );
+
+        // not enough money for the next reroll
+        assert!(!store.reroll(&mut player));
+        assert_eq!(player.money, 7);
+        assert_eq!(store.reroll_cost, 10);
+    }
+
+    #[test]
+    fn test_reroll_cost_uses_price_multiplier() {
+        let mut store = StoreResource {
+            slot_count: 1,
+            reroll_cost: 5,
+            price_multiplier: 2.0,
+            ..StoreResource::default()
+        };
+        let mut player = PlayerComponent {
+            money: 10,
+            ..PlayerComponent::default()
+        };
+
+        assert!(store.reroll(&mut player));
+        assert_eq!(player.money, 0);
+    }
+
+    #[test]
+    fn test_validate_slot_count() {
+        let mut store = StoreResource {
+            slot_count: STORE_MAX_SLOTS,
+            ..StoreResource::default()
+        };
+        assert!(store.validate().is_ok());
+
+        // slots without a bound buy action are rejected
+        store.slot_count = STORE_MAX_SLOTS + 1;
+        assert!(store.validate().is_err());
+
+        store.slot_count = 0;
+        assert!(store.validate().is_err());
+    }
+}
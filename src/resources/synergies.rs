@@ -0,0 +1,21 @@
+use crate::entities::ItemType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// extra effects granted for owning a combination of items
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Synergy {
+    pub name: String,
+    pub description: String,
+    pub items: Vec<ItemType>,
+    #[serde(default)]
+    pub stat_effects: HashMap<String, f32>,
+    #[serde(default)]
+    pub bool_effects: HashMap<String, bool>,
+}
+
+// synergies, read from synergies.ron
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct SynergiesResource {
+    pub synergies: Vec<Synergy>,
+}
@@ -0,0 +1,195 @@
+use super::{
+    high_scores::{create_text, load_font},
+    LeaderboardState, MainGameState,
+};
+use crate::{
+    constants::HIGHLIGHT_COLOR,
+    resources::{DifficultyType, GameParametersResource, SaveGame},
+};
+use amethyst::{
+    ecs::prelude::Entity,
+    input::{is_key_down, VirtualKeyCode},
+    prelude::*,
+    ui::UiText,
+};
+
+const DIFFICULTIES: [DifficultyType; 3] = [
+    DifficultyType::Easy,
+    DifficultyType::Normal,
+    DifficultyType::Hard,
+];
+
+// difficulty and endless mode choice shown before each run, skipped when a saved run is resumed
+#[derive(Default)]
+pub struct DifficultySelectState {
+    selected: usize,
+    endless_mode: bool,
+    difficulty_texts: Vec<Entity>,
+    endless_text: Option<Entity>,
+    ui_entities: Vec<Entity>,
+}
+
+impl DifficultySelectState {
+    fn update_highlight(&self, world: &mut World) {
+        let mut ui_texts = world.write_storage::<UiText>();
+        for (idx, difficulty_text) in self.difficulty_texts.iter().enumerate() {
+            if let Some(text) = ui_texts.get_mut(*difficulty_text) {
+                text.color = if idx == self.selected {
+                    HIGHLIGHT_COLOR
+                } else {
+                    [1.0, 1.0, 1.0, 1.0]
+                };
+            }
+        }
+    }
+
+    fn update_endless_text(&self, world: &mut World) {
+        if let Some(endless_text) = self.endless_text {
+            if let Some(text) = world.write_storage::<UiText>().get_mut(endless_text) {
+                text.text = endless_mode_text(self.endless_mode);
+            }
+        }
+    }
+
+    fn create_menu(&mut self, world: &mut World) {
+        let font = load_font(world);
+
+        self.ui_entities.push(create_text(
+            world,
+            &font,
+            "choose a difficulty".to_string(),
+            70.0,
+            25.0,
+        ));
+
+        for (idx, difficulty) in DIFFICULTIES.iter().enumerate() {
+            let difficulty_text = create_text(
+                world,
+                &font,
+                format!("{:?}", difficulty).to_lowercase(),
+                20.0 - idx as f32 * 30.0,
+                20.0,
+            );
+            self.difficulty_texts.push(difficulty_text);
+            self.ui_entities.push(difficulty_text);
+        }
+
+        let endless_text = create_text(
+            world,
+            &font,
+            endless_mode_text(self.endless_mode),
+            -75.0,
+            15.0,
+        );
+        self.endless_text = Some(endless_text);
+        self.ui_entities.push(endless_text);
+
+        self.ui_entities.push(create_text(
+            world,
+            &font,
+            "up and down to choose, enter to start".to_string(),
+            -105.0,
+            12.0,
+        ));
+        self.ui_entities.push(create_text(
+            world,
+            &font,
+            "e for endless mode, l for high scores".to_string(),
+            -125.0,
+            12.0,
+        ));
+
+        self.update_highlight(world);
+    }
+
+    fn delete_menu(&mut self, world: &mut World) {
+        world
+            .delete_entities(&self.ui_entities)
+            .expect("unable to delete difficulty select text");
+        self.ui_entities.clear();
+        self.difficulty_texts.clear();
+        self.endless_text = None;
+    }
+}
+
+fn endless_mode_text(endless_mode: bool) -> String {
+    format!("endless mode {}", if endless_mode { "on" } else { "off" })
+}
+
+impl SimpleState for DifficultySelectState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        // the difficulty and endless mode from game_parameters.ron are selected at first
+        let (current_difficulty, endless_mode) = {
+            let game_parameters = world.read_resource::<GameParametersResource>();
+            (
+                game_parameters.difficulty.clone(),
+                game_parameters.endless_mode,
+            )
+        };
+        self.selected = DIFFICULTIES
+            .iter()
+            .position(|difficulty| *difficulty == current_difficulty)
+            .unwrap_or(1);
+        self.endless_mode = endless_mode;
+
+        self.create_menu(world);
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        self.delete_menu(data.world);
+    }
+
+    // the menu is hidden while the leaderboard is open
+    fn on_pause(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        self.delete_menu(data.world);
+    }
+
+    fn on_resume(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        self.create_menu(data.world);
+    }
+
+    fn update(&mut self, _data: &mut StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
+        // a saved run keeps the difficulty it was started with
+        if SaveGame::exists() {
+            return Trans::Switch(Box::new(MainGameState::default()));
+        }
+        Trans::None
+    }
+
+    fn handle_event(
+        &mut self,
+        data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Window(event) = &event {
+            if is_key_down(&event, VirtualKeyCode::Up) {
+                self.selected = (self.selected + DIFFICULTIES.len() - 1) % DIFFICULTIES.len();
+                self.update_highlight(data.world);
+            }
+
+            if is_key_down(&event, VirtualKeyCode::Down) {
+                self.selected = (self.selected + 1) % DIFFICULTIES.len();
+                self.update_highlight(data.world);
+            }
+
+            if is_key_down(&event, VirtualKeyCode::L) {
+                return Trans::Push(Box::new(LeaderboardState::default()));
+            }
+
+            if is_key_down(&event, VirtualKeyCode::E) {
+                self.endless_mode = !self.endless_mode;
+                self.update_endless_text(data.world);
+            }
+
+            if is_key_down(&event, VirtualKeyCode::Return) {
+                let mut game_parameters = data.world.write_resource::<GameParametersResource>();
+                game_parameters.difficulty = DIFFICULTIES[self.selected].clone();
+                game_parameters.endless_mode = self.endless_mode;
+                return Trans::Switch(Box::new(MainGameState::default()));
+            }
+        }
+        Trans::None
+    }
+}
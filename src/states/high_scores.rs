@@ -0,0 +1,272 @@
+use super::DifficultySelectState;
+use crate::{
+    constants::{HIGHLIGHT_COLOR, HIGH_SCORE_NAME_LENGTH},
+    resources::{HighScoreEntry, HighScoresResource},
+};
+use amethyst::{
+    assets::Loader,
+    ecs::prelude::Entity,
+    input::{is_key_down, VirtualKeyCode},
+    prelude::*,
+    ui::{Anchor, FontHandle, LineMode, TtfFormat, UiText, UiTransform},
+    winit::{Event, WindowEvent},
+};
+
+// name entry shown after a run ends
+pub struct HighScoreEntryState {
+    entry: HighScoreEntry,
+    name_text: Option<Entity>,
+    ui_entities: Vec<Entity>,
+}
+
+impl HighScoreEntryState {
+    pub fn new(entry: HighScoreEntry) -> Self {
+        HighScoreEntryState {
+            entry,
+            name_text: None,
+            ui_entities: vec![],
+        }
+    }
+
+    fn update_name_text(&self, world: &mut World) {
+        if let Some(name_text) = self.name_text {
+            if let Some(text) = world.write_storage::<UiText>().get_mut(name_text) {
+                text.text = format!("{}_", self.entry.name);
+            }
+        }
+    }
+}
+
+impl SimpleState for HighScoreEntryState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+        let font = load_font(world);
+
+        self.ui_entities.push(create_text(
+            world,
+            &font,
+            "game over".to_string(),
+            70.0,
+            30.0,
+        ));
+        self.ui_entities.push(create_text(
+            world,
+            &font,
+            format!("score {}", self.entry.score),
+            35.0,
+            20.0,
+        ));
+        self.ui_entities.push(create_text(
+            world,
+            &font,
+            "enter your name".to_string(),
+            0.0,
+            15.0,
+        ));
+
+        let name_text = create_text(world, &font, "_".to_string(), -30.0, 20.0);
+        self.name_text = Some(name_text);
+        self.ui_entities.push(name_text);
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        data.world
+            .delete_entities(&self.ui_entities)
+            .expect("unable to delete high score entry text");
+    }
+
+    fn handle_event(
+        &mut self,
+        data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Window(event) = &event {
+            if is_key_down(&event, VirtualKeyCode::Return) {
+                if self.entry.name.is_empty() {
+                    self.entry.name = "anon".to_string();
+                }
+
+                let mut high_scores = HighScoresResource::load();
+                let rank = high_scores.insert(self.entry.clone());
+                high_scores.save();
+
+                return Trans::Switch(Box::new(LeaderboardState::after_run(high_scores, rank)));
+            }
+
+            if is_key_down(&event, VirtualKeyCode::Back) {
+                self.entry.name.pop();
+                self.update_name_text(data.world);
+            }
+
+            if let Event::WindowEvent {
+                event: WindowEvent::ReceivedCharacter(character),
+                ..
+            } = event
+            {
+                if character.is_ascii_alphanumeric()
+                    && self.entry.name.len() < HIGH_SCORE_NAME_LENGTH
+                {
+                    self.entry.name.push(*character);
+                    self.update_name_text(data.world);
+                }
+            }
+        }
+        Trans::None
+    }
+}
+
+// table of the best runs, closed with escape
+pub struct LeaderboardState {
+    high_scores: HighScoresResource,
+    highlighted_rank: Option<usize>,
+    ui_entities: Vec<Entity>,
+    after_run: bool, // reached from a finished run rather than from a menu
+}
+
+impl LeaderboardState {
+    pub fn new(high_scores: HighScoresResource, highlighted_rank: Option<usize>) -> Self {
+        LeaderboardState {
+            high_scores,
+            highlighted_rank,
+            ui_entities: vec![],
+            after_run: false,
+        }
+    }
+
+    // leaderboard shown after a run ends, closing it starts a new run
+    pub fn after_run(high_scores: HighScoresResource, highlighted_rank: Option<usize>) -> Self {
+        LeaderboardState {
+            after_run: true,
+            ..LeaderboardState::new(high_scores, highlighted_rank)
+        }
+    }
+}
+
+impl Default for LeaderboardState {
+    fn default() -> Self {
+        LeaderboardState::new(HighScoresResource::load(), None)
+    }
+}
+
+impl SimpleState for LeaderboardState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+        let font = load_font(world);
+
+        self.ui_entities.push(create_text(
+            world,
+            &font,
+            "high scores".to_string(),
+            120.0,
+            25.0,
+        ));
+
+        if self.high_scores.entries.is_empty() {
+            self.ui_entities.push(create_text(
+                world,
+                &font,
+                "no runs yet".to_string(),
+                70.0,
+                15.0,
+            ));
+        }
+
+        for (rank, entry) in self.high_scores.entries.iter().enumerate() {
+            let row = create_text(
+                world,
+                &font,
+                format!(
+                    "{}. {}  {}  {} {:?}{}  loop {} phase {}",
+                    rank + 1,
+                    entry.name,
+                    entry.score,
+                    entry.character,
+                    entry.difficulty,
+                    if entry.endless_mode { " endless" } else { "" },
+                    entry.loop_reached + 1,
+                    entry.phase_reached + 1,
+                ),
+                85.0 - rank as f32 * 22.0,
+                14.0,
+            );
+
+            // highlight the run that was just entered
+            if Some(rank) == self.highlighted_rank {
+                if let Some(text) = world.write_storage::<UiText>().get_mut(row) {
+                    text.color = HIGHLIGHT_COLOR;
+                }
+            }
+
+            self.ui_entities.push(row);
+        }
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        // the finished run's entities are cleared along with the table before the next run
+        if self.after_run {
+            data.world.delete_all();
+        } else {
+            data.world
+                .delete_entities(&self.ui_entities)
+                .expect("unable to delete leaderboard text");
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Window(event) = &event {
+            if is_key_down(&event, VirtualKeyCode::Escape) {
+                if self.after_run {
+                    return Trans::Switch(Box::new(DifficultySelectState::default()));
+                }
+                return Trans::Pop;
+            }
+        }
+        Trans::None
+    }
+}
+
+pub(super) fn load_font(world: &mut World) -> FontHandle {
+    world.read_resource::<Loader>().load(
+        "font/SpaceMadness.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    )
+}
+
+pub(super) fn create_text(
+    world: &mut World,
+    font: &FontHandle,
+    text: String,
+    y: f32,
+    font_size: f32,
+) -> Entity {
+    let ui_transform = UiTransform::new(
+        text.clone(),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.0,
+        y,
+        1.0,
+        600.0,
+        font_size + 10.0,
+    );
+    let ui_text = UiText::new(
+        font.clone(),
+        text,
+        [1.0, 1.0, 1.0, 1.0],
+        font_size,
+        LineMode::Single,
+        Anchor::Middle,
+    );
+
+    world
+        .create_entity()
+        .with(ui_transform)
+        .with(ui_text)
+        .build()
+}
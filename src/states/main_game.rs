@@ -1,34 +1,53 @@
 use crate::{
     audio::initialize_audio,
+    components::{DefenseTag, EffectTag, HealthComponent, PlayerComponent},
     constants::{
         ARENA_HEIGHT, ARENA_MAX_X, ARENA_MIN_X, ARENA_MIN_Y, ARENA_WIDTH, CAMERA_X, CAMERA_Y,
-        CAMERA_Z,
+        CAMERA_Z, DEFAULT_CHARACTER, HIGHLIGHT_COLOR, STORE_PRICE_SPACING_X, STORE_PRICE_SPACING_Y,
+        STORE_PRICE_X, STORE_PRICE_Y, STORE_SLOTS_PER_COLUMN, STORE_SLOT_Z, UNLOCK_TOAST_COLOR,
     },
+    data_include::load_include_data,
     entities::{
         initialize_arena_barriers, initialize_background, initialize_defense,
         initialize_enemy_spawner, initialize_planet, initialize_side_panels, initialize_spaceship,
-        initialize_status_bars, initialize_store_icons,
+        initialize_status_bars, initialize_store_icons, spawn_spawnable, spawn_toast,
+        store_slot_position, SpawnableType,
     },
-    resources::{DebugLinesConfig, SpriteSheetsConfig, SpriteSheetsResource},
-    states::PausedState,
+    events::RunEndedEvent,
+    resources::{
+        AchievementProgressResource, ConsumablesResource, DebugLinesConfig, EffectsResource,
+        EnemiesResource, FormationsResource, GameParametersResource, HighScoreEntry, ItemsResource,
+        PhaseManagerResource, PlayersResource, ProfileResource, RunStatsResource,
+        RunSummaryResource, SaveGame, ScoreResource, SpriteSheetsConfig, SpriteSheetsResource,
+        StoreResource, UnlockReward, UnlocksResource,
+    },
+    states::{high_scores::load_font, HighScoreEntryState, PausedState},
     systems,
 };
 use amethyst::{
     assets::{AssetStorage, Loader},
+    config::Config,
     core::transform::Transform,
-    ecs::prelude::{Dispatcher, DispatcherBuilder, Entity},
-    input::{is_key_down, VirtualKeyCode},
+    ecs::prelude::{Dispatcher, DispatcherBuilder, Entities, Entity, Join, LazyUpdate, ReadExpect},
+    input::{is_close_requested, is_key_down, VirtualKeyCode},
     prelude::*,
     renderer::debug_drawing::{DebugLines, DebugLinesParams},
     renderer::formats::texture::ImageFormat,
     renderer::{Camera, SpriteRender, SpriteSheet, SpriteSheetFormat, Texture},
+    shrev::EventChannel,
     ui::{Anchor, LineMode, TtfFormat, UiText, UiTransform},
 };
-use std::{collections::HashMap, f32::consts::FRAC_PI_3};
+use rand::{thread_rng, Rng};
+use std::{
+    collections::HashMap,
+    f32::consts::FRAC_PI_3,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 pub struct MainGameState {
     is_paused: bool,
     pause_display: Option<Entity>,
+    run_ended: bool, // RunEndedEvent has been sent and systems get a frame to handle it
     dispatcher: Dispatcher<'static, 'static>,
 }
 
@@ -37,17 +56,32 @@ impl Default for MainGameState {
         MainGameState {
             is_paused: false,
             pause_display: None,
+            run_ended: false,
             dispatcher: DispatcherBuilder::new()
                 .with(systems::AnimationSystem, "animation_system", &[])
                 .with(systems::PlanetsSystem, "planets_system", &[])
-                .with(systems::PhaseManagerSystem, "phase_manager_system", &[])
+                .with(
+                    systems::PhaseManagerSystem::default(),
+                    "phase_manager_system",
+                    &[],
+                )
                 .with(systems::EnemyBehaviorSystem, "enemy_behavior_system", &[])
                 .with(systems::BossSystem, "boss_system", &[])
-                .with(systems::SpawnerSystem, "spawner_system", &[])
+                .with(systems::SpawnerSystem::default(), "spawner_system", &[])
+                .with(systems::DifficultySystem, "difficulty_system", &[])
                 .with(systems::TimeLimitSystem, "timelimit_system", &[])
+                .with(systems::PoisonSystem, "poison_system", &[])
+                .with(systems::ShieldSystem, "shield_system", &[])
                 .with(systems::Motion2DSystem, "motion_2d_system", &[])
+                .with(systems::PowerUpSystem, "power_up_system", &[])
+                .with(
+                    systems::PickupMagnetSystem::default(),
+                    "pickup_magnet_system",
+                    &["motion_2d_system", "power_up_system"],
+                )
                 .with(systems::EnemyTargetSystem, "enemy_target_system", &[])
                 .with(systems::AutoSpawnerSystem, "auto_spawner_system", &[])
+                .with(systems::BlastHomingSystem, "blast_homing_system", &[])
                 .with(
                     systems::BarrelRollAbilitySystem::default(),
                     "barrel_roll_ability_system",
@@ -58,6 +92,11 @@ impl Default for MainGameState {
                     "enemy_motion_2d_system",
                     &["enemy_target_system"],
                 )
+                .with(
+                    systems::PathFollowSystem,
+                    "path_follow_system",
+                    &["enemy_target_system"],
+                )
                 .with(
                     systems::DespawnAtBorderSystem,
                     "despawn_at_border_system",
@@ -79,6 +118,11 @@ impl Default for MainGameState {
                     "collision_handler_system",
                     &["collision_detection_system"],
                 )
+                .with(
+                    systems::BlastArenaBorderCollisionSystem::default(),
+                    "blast_arena_border_collision_system",
+                    &["collision_handler_system"],
+                )
                 .with(
                     systems::EnemyArenaBorderCollisionSystem::default(),
                     "enemy_arena_border_collsion_system",
@@ -134,18 +178,73 @@ impl Default for MainGameState {
                     "spaceship_system",
                     &["spaceship_item_collision_system"],
                 )
-                .with(systems::StoreSystem, "store_system", &[])
+                .with(
+                    systems::BombSystem::default(),
+                    "bomb_system",
+                    &["spaceship_item_collision_system"],
+                )
+                .with(
+                    systems::DamageSystem::default(),
+                    "damage_system",
+                    &[
+                        "enemy_arena_border_collsion_system",
+                        "spaceship_arena_border_collision_system",
+                        "spaceship_enemy_collision_system",
+                        "spaceship_blast_collision_system",
+                        "enemy_player_collision",
+                        "enemy_enemy_collision",
+                        "enemy_blast_collision",
+                        "defense_system",
+                        "bomb_system",
+                    ],
+                )
+                .with(systems::StoreSystem::default(), "store_system", &[])
                 .with(
                     systems::StatTrackerSystem,
                     "stat_tracker_system",
-                    &["store_system", "spaceship_system"],
+                    &["store_system", "spaceship_system", "bomb_system"],
+                )
+                .with(
+                    systems::SynergySystem::default(),
+                    "synergy_system",
+                    &["spaceship_item_collision_system"],
+                )
+                .with(
+                    systems::InventoryDisplaySystem::default(),
+                    "inventory_display_system",
+                    &["synergy_system"],
                 )
                 .with(systems::AutoFireSystem, "autoblaster_system", &[])
                 .with(systems::ManualBlasterSystem, "manualblaster_system", &[])
+                .with(
+                    systems::HitFeedbackSystem::default(),
+                    "hit_feedback_system",
+                    &["damage_system"],
+                )
+                .with(
+                    systems::ScoreSystem::default(),
+                    "score_system",
+                    &["damage_system", "phase_manager_system"],
+                )
+                .with(
+                    systems::RunStatsSystem::default(),
+                    "run_stats_system",
+                    &["damage_system", "phase_manager_system"],
+                )
+                .with(
+                    systems::AchievementSystem::default(),
+                    "achievement_system",
+                    &["damage_system", "phase_manager_system"],
+                )
                 .with(
                     systems::EnemyDestroyedSystem::default(),
                     "enemy_destroyed_system",
-                    &["enemy_behavior_system"],
+                    &[
+                        "damage_system",
+                        "hit_feedback_system",
+                        "achievement_system",
+                        "run_stats_system",
+                    ],
                 )
                 .with(
                     systems::PlayAudioSystem::default(),
@@ -153,6 +252,11 @@ impl Default for MainGameState {
                     &[],
                 )
                 .with(systems::FadeSystem, "fade_system", &[])
+                .with(
+                    systems::DamageNumberSystem,
+                    "damage_number_system",
+                    &["fade_system", "motion_2d_system"],
+                )
                 .build(),
         }
     }
@@ -164,6 +268,8 @@ impl SimpleState for MainGameState {
         let spritesheets = init_spritesheets(world);
 
         self.dispatcher.setup(world);
+        // no system reads the effect tag, it is only used to find effects when saving
+        world.register::<EffectTag>();
 
         initialize_audio(world);
         initialise_ui(world);
@@ -191,13 +297,25 @@ impl SimpleState for MainGameState {
             0.005,
         );
         initialize_background(world, spritesheets.spritesheets["backgrounds"].clone());
+        initialize_run(world);
         initialize_spaceship(world, spritesheets.spritesheets["characters"].clone());
         initialize_enemy_spawner(world);
         initialize_arena_barriers(world);
         //initialize_store(world);
-        initialize_store_icons(world, spritesheets.spritesheets["items"].clone());
+        let slot_count = world.read_resource::<StoreResource>().slot_count;
+        initialize_store_icons(
+            world,
+            spritesheets.spritesheets["items"].clone(),
+            slot_count,
+        );
         initialise_camera(world);
 
+        // continue a run that was saved when the window was closed
+        match SaveGame::take() {
+            Some(save_game) => save_game.restore(world),
+            None => initialize_starting_loadout(world),
+        }
+
         world.insert(DebugLines::new());
         let debug_lines_params = {
             let debug_lines_config = world.read_resource::<DebugLinesConfig>();
@@ -232,15 +350,45 @@ impl SimpleState for MainGameState {
             self.pause_display = None;
         }
 
+        if self.run_ended {
+            record_run(data.world);
+            return Trans::Switch(Box::new(HighScoreEntryState::new(high_score_entry(
+                data.world,
+            ))));
+        }
+
+        if run_over(data.world) {
+            let run_ended_event = {
+                let phase_manager = data.world.read_resource::<PhaseManagerResource>();
+                RunEndedEvent::new(
+                    data.world.read_resource::<ScoreResource>().score,
+                    phase_manager.phase_idx,
+                    phase_manager.loop_count,
+                )
+            };
+            data.world
+                .write_resource::<EventChannel<RunEndedEvent>>()
+                .single_write(run_ended_event);
+            self.run_ended = true;
+        }
+
         Trans::None
     }
 
     fn handle_event(
         &mut self,
-        _data: StateData<'_, GameData<'_, '_>>,
+        data: StateData<'_, GameData<'_, '_>>,
         event: StateEvent,
     ) -> SimpleTrans {
         if let StateEvent::Window(event) = &event {
+            if is_close_requested(&event) {
+                SaveGame::capture(data.world).save();
+                data.world
+                    .read_resource::<AchievementProgressResource>()
+                    .save();
+                return Trans::Quit;
+            }
+
             if is_key_down(&event, VirtualKeyCode::Escape) {
                 return Trans::Push(Box::new(PausedState));
             }
@@ -249,6 +397,210 @@ impl SimpleState for MainGameState {
     }
 }
 
+// record the run's choices, apply the chosen difficulty and the profile's unlocks
+fn initialize_run(world: &mut World) {
+    let (difficulty, difficulty_preset, character, endless_mode) = {
+        let game_parameters = world.read_resource::<GameParametersResource>();
+        (
+            game_parameters.difficulty.clone(),
+            game_parameters.difficulty_preset(),
+            game_parameters.character.clone(),
+            game_parameters.endless_mode,
+        )
+    };
+
+    let unlocks = (*world.read_resource::<UnlocksResource>()).clone();
+    let mut profile = (*world.read_resource::<ProfileResource>()).clone();
+
+    let newly_unlocked = profile.update_unlocks(&unlocks);
+    if !newly_unlocked.is_empty() {
+        let font = load_font(world);
+        let (entities, lazy_update) = world.system_data::<(Entities, ReadExpect<LazyUpdate>)>();
+
+        for (stack_idx, unlock) in newly_unlocked.iter().enumerate() {
+            spawn_toast(
+                format!("unlocked: {}", unlock.description),
+                UNLOCK_TOAST_COLOR,
+                stack_idx,
+                font.clone(),
+                &entities,
+                &lazy_update,
+            );
+        }
+        profile.save();
+    }
+
+    // the store, phases and formations start each run from their data files so earlier runs
+    // don't carry over
+    let mut store = <StoreResource as Config>::load_bytes(load_include_data().store)
+        .expect("failed to load data file: store.ron");
+    store.price_multiplier = difficulty_preset.store_prices;
+    store.restock_period *= difficulty_preset.restock_period;
+    store.resize_slots();
+
+    for reward in unlocks.rewards(&profile) {
+        if let UnlockReward::StoreStock(spawnable_type, probability) = reward {
+            store
+                .stock_probs
+                .push((spawnable_type.clone(), *probability));
+        }
+    }
+    world.insert(store);
+
+    let phases = <PhaseManagerResource as Config>::load_bytes(load_include_data().phases)
+        .expect("failed to load data file: phases.ron");
+    world.insert(phases);
+    let formations = <FormationsResource as Config>::load_bytes(load_include_data().formations)
+        .expect("failed to load data file: formations.ron");
+    world.insert(formations);
+    world.insert(ScoreResource::default());
+
+    // locked and unknown characters fall back to the default character
+    let character = match character {
+        Some(character)
+            if world
+                .read_resource::<PlayersResource>()
+                .contains_key(&character)
+                && unlocks.character_unlocked(&character, &profile) =>
+        {
+            character
+        }
+        Some(character) => {
+            log::warn!("character {} is not available", character);
+            DEFAULT_CHARACTER.to_string()
+        }
+        None => DEFAULT_CHARACTER.to_string(),
+    };
+
+    world.insert(profile);
+    world.insert(RunStatsResource::default());
+    world.insert(RunSummaryResource {
+        character,
+        difficulty,
+        run_id: thread_rng().gen(),
+        endless_mode,
+        ..Default::default()
+    });
+}
+
+// give the spaceship the starting loadouts the profile has unlocked
+fn initialize_starting_loadout(world: &mut World) {
+    let loadout: Vec<SpawnableType> = {
+        let unlocks = world.read_resource::<UnlocksResource>();
+        let profile = world.read_resource::<ProfileResource>();
+
+        unlocks
+            .rewards(&profile)
+            .filter_map(|reward| match reward {
+                UnlockReward::StartingLoadout(loadout) => Some(loadout.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    };
+
+    let spaceship_transform = (
+        &world.read_storage::<PlayerComponent>(),
+        &world.read_storage::<Transform>(),
+    )
+        .join()
+        .next()
+        .map(|(_, transform)| transform.clone());
+
+    // spawned on top of the spaceship so they're picked up straight away
+    if let Some(spaceship_transform) = spaceship_transform {
+        let (
+            entities,
+            lazy_update,
+            consumables_resource,
+            enemies_resource,
+            items_resource,
+            effects_resource,
+            spritesheets_resource,
+        ) = world.system_data::<(
+            Entities,
+            ReadExpect<LazyUpdate>,
+            ReadExpect<ConsumablesResource>,
+            ReadExpect<EnemiesResource>,
+            ReadExpect<ItemsResource>,
+            ReadExpect<EffectsResource>,
+            ReadExpect<SpriteSheetsResource>,
+        )>();
+
+        for spawnable_type in loadout.iter() {
+            spawn_spawnable(
+                spawnable_type,
+                spaceship_transform.clone(),
+                &consumables_resource,
+                &enemies_resource,
+                &items_resource,
+                &effects_resource,
+                &spritesheets_resource,
+                &entities,
+                &lazy_update,
+            );
+        }
+    }
+}
+
+// add the finished run to the profile's lifetime stats and export its stats
+fn record_run(world: &World) {
+    let run_summary = world.read_resource::<RunSummaryResource>();
+    let mut profile = world.write_resource::<ProfileResource>();
+
+    profile.record_run(
+        &run_summary,
+        &world.read_resource::<PhaseManagerResource>(),
+        &world.read_resource::<ScoreResource>(),
+    );
+    profile.save();
+
+    world
+        .read_resource::<RunStatsResource>()
+        .export(&run_summary, current_timestamp());
+}
+
+// the run is over once the player or the defense is destroyed
+fn run_over(world: &World) -> bool {
+    let players = world.read_storage::<PlayerComponent>();
+    let defense_tags = world.read_storage::<DefenseTag>();
+    let healths = world.read_storage::<HealthComponent>();
+
+    (&players, &healths)
+        .join()
+        .any(|(_, health)| health.value <= 0.0)
+        || (&defense_tags, &healths)
+            .join()
+            .any(|(_, health)| health.value <= 0.0)
+}
+
+// high score table entry for the finished run, named on the entry screen
+fn high_score_entry(world: &World) -> HighScoreEntry {
+    let run_summary = world.read_resource::<RunSummaryResource>();
+    let phase_manager = world.read_resource::<PhaseManagerResource>();
+    let score = world.read_resource::<ScoreResource>();
+
+    HighScoreEntry {
+        name: String::new(),
+        score: score.score,
+        character: run_summary.character.clone(),
+        run_id: run_summary.run_id,
+        phase_reached: phase_manager.phase_idx,
+        loop_reached: phase_manager.loop_count,
+        difficulty: run_summary.difficulty.clone(),
+        endless_mode: run_summary.endless_mode,
+        timestamp: current_timestamp(),
+    }
+}
+
+// seconds since the unix epoch
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
 fn init_spritesheets(world: &mut World) -> SpriteSheetsResource {
     let mut spritesheets = HashMap::new();
     {
@@ -296,9 +648,13 @@ fn initialise_camera(world: &mut World) {
 pub struct TrackedStats {
     pub currency: Entity,
     pub shields: Entity,
-    pub item_price_1: Entity,
-    pub item_price_2: Entity,
-    pub item_price_3: Entity,
+    pub bombs: Entity,
+    pub loop_count: Entity,
+    pub score: Entity,
+    pub combo: Entity,
+    pub power_ups: Entity,
+    pub reroll_cost: Entity,
+    pub item_prices: Vec<Entity>, // one price per store slot
 }
 
 fn initialise_ui(world: &mut World) {
@@ -324,19 +680,24 @@ fn initialise_ui(world: &mut World) {
         )
     };
 
-    let item_slots_sprite_render = SpriteRender {
+    // a single slot frame is drawn behind each store slot
+    let item_slot_sprite_render = SpriteRender {
         sprite_sheet: item_slots_sprite_sheet_handle,
-        sprite_number: 0,
+        sprite_number: 1,
     };
 
-    let mut local_transform = Transform::default();
-    local_transform.set_translation_xyz(ARENA_MAX_X + 10.0 + 2.0, ARENA_MIN_Y + 29.0 + 24.0, 0.9);
+    let slot_count = world.read_resource::<StoreResource>().slot_count;
+    for inventory_index in 0..slot_count {
+        let (x, y) = store_slot_position(inventory_index);
+        let mut local_transform = Transform::default();
+        local_transform.set_translation_xyz(x, y, STORE_SLOT_Z);
 
-    world
-        .create_entity()
-        .with(item_slots_sprite_render)
-        .with(local_transform)
-        .build();
+        world
+            .create_entity()
+            .with(item_slot_sprite_render.clone())
+            .with(local_transform)
+            .build();
+    }
 
     let currency_texture_handle = {
         let loader = world.read_resource::<Loader>();
@@ -397,7 +758,7 @@ fn initialise_ui(world: &mut World) {
         .build();
 
     let shield_sprite_render = SpriteRender {
-        sprite_sheet: consumables_sprite_sheet_handle,
+        sprite_sheet: consumables_sprite_sheet_handle.clone(),
         sprite_number: 4,
     };
 
@@ -410,6 +771,20 @@ fn initialise_ui(world: &mut World) {
         .with(local_transform)
         .build();
 
+    let bomb_sprite_render = SpriteRender {
+        sprite_sheet: consumables_sprite_sheet_handle,
+        sprite_number: 2,
+    };
+
+    let mut local_transform = Transform::default();
+    local_transform.set_translation_xyz(ARENA_MAX_X + 10.0, ARENA_MIN_Y + 138.0, 0.9);
+
+    world
+        .create_entity()
+        .with(bomb_sprite_render)
+        .with(local_transform)
+        .build();
+
     let font = world.read_resource::<Loader>().load(
         "font/SpaceMadness.ttf",
         TtfFormat,
@@ -463,81 +838,195 @@ fn initialise_ui(world: &mut World) {
         ))
         .build();
 
-    let item_price_1_transform = UiTransform::new(
-        "item_price_0".to_string(),
-        Anchor::BottomRight,
-        Anchor::BottomRight,
+    let bombs_count_transform = UiTransform::new(
+        "bombs_count".to_string(),
+        Anchor::MiddleRight,
+        Anchor::MiddleRight,
         -6.0,
-        130.0,
+        6.0,
         0.9,
         50.0,
         45.0,
     );
-    let item_price_1 = world
+
+    let bombs_count = world
         .create_entity()
-        .with(item_price_1_transform)
+        .with(bombs_count_transform)
         .with(UiText::new(
             font.clone(),
-            "$0".to_string(),
+            "x0".to_string(),
             [1.0, 1.0, 1.0, 1.0],
-            15.0,
+            20.0,
             LineMode::Single,
             Anchor::Middle,
         ))
         .build();
 
-    let item_price_2_transform = UiTransform::new(
-        "item_price_1".to_string(),
-        Anchor::BottomRight,
-        Anchor::BottomRight,
+    let loop_count_transform = UiTransform::new(
+        "loop_count".to_string(),
+        Anchor::TopLeft,
+        Anchor::TopLeft,
+        6.0,
         -6.0,
-        90.0,
         0.9,
-        50.0,
-        45.0,
+        80.0,
+        30.0,
     );
-    let item_price_2 = world
+
+    let loop_count = world
         .create_entity()
-        .with(item_price_2_transform)
+        .with(loop_count_transform)
         .with(UiText::new(
             font.clone(),
-            "$0".to_string(),
+            "".to_string(),
             [1.0, 1.0, 1.0, 1.0],
-            15.0,
+            16.0,
             LineMode::Single,
-            Anchor::Middle,
+            Anchor::MiddleLeft,
         ))
         .build();
 
-    let item_price_3_transform = UiTransform::new(
-        "item_price_2".to_string(),
-        Anchor::BottomRight,
-        Anchor::BottomRight,
+    let score_transform = UiTransform::new(
+        "score".to_string(),
+        Anchor::TopLeft,
+        Anchor::TopLeft,
+        6.0,
+        -30.0,
+        0.9,
+        80.0,
+        30.0,
+    );
+
+    let score = world
+        .create_entity()
+        .with(score_transform)
+        .with(UiText::new(
+            font.clone(),
+            "0".to_string(),
+            [1.0, 1.0, 1.0, 1.0],
+            16.0,
+            LineMode::Single,
+            Anchor::MiddleLeft,
+        ))
+        .build();
+
+    let combo_transform = UiTransform::new(
+        "combo".to_string(),
+        Anchor::TopLeft,
+        Anchor::TopLeft,
+        6.0,
+        -54.0,
+        0.9,
+        80.0,
+        30.0,
+    );
+
+    let combo = world
+        .create_entity()
+        .with(combo_transform)
+        .with(UiText::new(
+            font.clone(),
+            "".to_string(),
+            HIGHLIGHT_COLOR,
+            14.0,
+            LineMode::Single,
+            Anchor::MiddleLeft,
+        ))
+        .build();
+
+    // active power-ups and their remaining durations
+    let power_ups_transform = UiTransform::new(
+        "power_ups".to_string(),
+        Anchor::TopRight,
+        Anchor::TopRight,
+        -6.0,
         -6.0,
-        50.0,
         0.9,
-        50.0,
-        45.0,
+        120.0,
+        90.0,
     );
-    let item_price_3 = world
+
+    let power_ups = world
+        .create_entity()
+        .with(power_ups_transform)
+        .with(UiText::new(
+            font.clone(),
+            "".to_string(),
+            [0.45, 1.0, 0.45, 1.0],
+            12.0,
+            LineMode::Wrap,
+            Anchor::TopRight,
+        ))
+        .build();
+
+    let reroll_cost_transform = UiTransform::new(
+        "reroll_cost".to_string(),
+        Anchor::MiddleRight,
+        Anchor::MiddleRight,
+        -6.0,
+        -30.0,
+        0.9,
+        60.0,
+        30.0,
+    );
+
+    let reroll_cost = world
         .create_entity()
-        .with(item_price_3_transform)
+        .with(reroll_cost_transform)
         .with(UiText::new(
-            font,
-            "$0".to_string(),
+            font.clone(),
+            "".to_string(),
             [1.0, 1.0, 1.0, 1.0],
-            15.0,
+            10.0,
             LineMode::Single,
             Anchor::Middle,
         ))
         .build();
 
+    // prices are laid out in the same columns as the store slots, with the last column at the edge
+    let column_count = (slot_count + STORE_SLOTS_PER_COLUMN - 1) / STORE_SLOTS_PER_COLUMN;
+    let mut item_prices = vec![];
+    for inventory_index in 0..slot_count {
+        let column = inventory_index / STORE_SLOTS_PER_COLUMN;
+        let row = inventory_index % STORE_SLOTS_PER_COLUMN;
+
+        let item_price_transform = UiTransform::new(
+            format!("item_price_{}", inventory_index),
+            Anchor::BottomRight,
+            Anchor::BottomRight,
+            STORE_PRICE_X - (column_count - 1 - column) as f32 * STORE_PRICE_SPACING_X,
+            STORE_PRICE_Y - row as f32 * STORE_PRICE_SPACING_Y,
+            0.9,
+            50.0,
+            45.0,
+        );
+
+        item_prices.push(
+            world
+                .create_entity()
+                .with(item_price_transform)
+                .with(UiText::new(
+                    font.clone(),
+                    "$0".to_string(),
+                    [1.0, 1.0, 1.0, 1.0],
+                    15.0,
+                    LineMode::Single,
+                    Anchor::Middle,
+                ))
+                .build(),
+        );
+    }
+
     world.insert(TrackedStats {
         currency: currency_count,
         shields: shields_count,
-        item_price_1,
-        item_price_2,
-        item_price_3,
+        bombs: bombs_count,
+        loop_count,
+        score,
+        combo,
+        power_ups,
+        reroll_cost,
+        item_prices,
     });
 }
 
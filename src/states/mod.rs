@@ -1,7 +1,11 @@
+mod difficulty_select;
+mod high_scores;
 mod main_game;
 mod paused;
 
 pub use self::{
+    difficulty_select::DifficultySelectState,
+    high_scores::{HighScoreEntryState, LeaderboardState},
     main_game::{MainGameState, TrackedStats},
     paused::PausedState,
 };
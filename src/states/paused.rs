@@ -1,5 +1,9 @@
+use crate::{
+    resources::{AchievementProgressResource, SaveGame},
+    states::LeaderboardState,
+};
 use amethyst::{
-    input::{is_key_down, VirtualKeyCode},
+    input::{is_close_requested, is_key_down, VirtualKeyCode},
     prelude::*,
 };
 
@@ -8,13 +12,25 @@ pub struct PausedState;
 impl SimpleState for PausedState {
     fn handle_event(
         &mut self,
-        _data: StateData<'_, GameData<'_, '_>>,
+        data: StateData<'_, GameData<'_, '_>>,
         event: StateEvent,
     ) -> SimpleTrans {
         if let StateEvent::Window(event) = &event {
+            if is_close_requested(&event) {
+                SaveGame::capture(data.world).save();
+                data.world
+                    .read_resource::<AchievementProgressResource>()
+                    .save();
+                return Trans::Quit;
+            }
+
             if is_key_down(&event, VirtualKeyCode::Escape) {
                 return Trans::Pop;
             }
+
+            if is_key_down(&event, VirtualKeyCode::L) {
+                return Trans::Push(Box::new(LeaderboardState::default()));
+            }
         }
         Trans::None
     }
@@ -4,6 +4,7 @@ use crate::{
         EnemyComponent, Motion2DComponent,
     },
     events::PlayerCollisionEvent,
+    resources::RunStatsResource,
 };
 use amethyst::{
     core::timing::Time,
@@ -27,6 +28,7 @@ impl<'s> System<'s> for BarrelRollAbilitySystem {
         WriteStorage<'s, Motion2DComponent>,
         ReadStorage<'s, EnemyComponent>,
         ReadStorage<'s, BarrierComponent>,
+        Write<'s, RunStatsResource>,
     );
 
     fn setup(&mut self, world: &mut World) {
@@ -48,12 +50,19 @@ impl<'s> System<'s> for BarrelRollAbilitySystem {
             mut motion2ds,
             enemies,
             barriers,
+            mut run_stats,
         ): Self::SystemData,
     ) {
         for (barrel_roll_ability, motion2d) in (&mut barrel_roll_abilities, &mut motion2ds).join() {
             // execute barrel roll on input down
+            let execute_timer = barrel_roll_ability.execute_timer;
             barrel_roll_ability.execute_action(&input);
 
+            // the cooldown restarts when a barrel roll begins
+            if barrel_roll_ability.execute_timer > execute_timer {
+                run_stats.barrel_rolls += 1;
+            }
+
             // update ability and timers
             barrel_roll_ability.update(time.delta_seconds());
 
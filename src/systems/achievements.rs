@@ -0,0 +1,224 @@
+use crate::{
+    components::{EnemyComponent, PlayerComponent, ToastTag},
+    constants::HIGHLIGHT_COLOR,
+    entities::{spawn_toast, SpawnableType},
+    events::{
+        EnemyDestroyedEvent, EnemyReachedBottomEvent, EntityDamagedEvent, ItemGetEvent,
+        PlayerCollisionEvent, RunEndedEvent,
+    },
+    resources::{
+        AchievementProgressResource, AchievementRule, AchievementsResource, PhaseManagerResource,
+        RunSummaryResource,
+    },
+};
+use amethyst::{
+    assets::Loader,
+    ecs::*,
+    shrev::{EventChannel, ReaderId},
+    ui::{FontHandle, TtfFormat},
+};
+use std::collections::HashSet;
+
+#[derive(Default)]
+pub struct AchievementSystem {
+    enemy_destroyed_event_reader: Option<ReaderId<EnemyDestroyedEvent>>,
+    item_get_event_reader: Option<ReaderId<ItemGetEvent>>,
+    enemy_reached_bottom_event_reader: Option<ReaderId<EnemyReachedBottomEvent>>,
+    player_collision_event_reader: Option<ReaderId<PlayerCollisionEvent>>,
+    entity_damaged_event_reader: Option<ReaderId<EntityDamagedEvent>>,
+    run_ended_event_reader: Option<ReaderId<RunEndedEvent>>,
+    rammed_enemies: HashSet<Entity>, // collisions repeat while overlapping, so each enemy counts once
+    font: Option<FontHandle>,
+}
+
+impl<'s> System<'s> for AchievementSystem {
+    type SystemData = (
+        Read<'s, EventChannel<EnemyDestroyedEvent>>,
+        Read<'s, EventChannel<ItemGetEvent>>,
+        Read<'s, EventChannel<EnemyReachedBottomEvent>>,
+        Read<'s, EventChannel<PlayerCollisionEvent>>,
+        Read<'s, EventChannel<EntityDamagedEvent>>,
+        Read<'s, EventChannel<RunEndedEvent>>,
+        Entities<'s>,
+        ReadStorage<'s, EnemyComponent>,
+        ReadStorage<'s, PlayerComponent>,
+        ReadStorage<'s, ToastTag>,
+        ReadExpect<'s, AchievementsResource>,
+        WriteExpect<'s, AchievementProgressResource>,
+        Read<'s, PhaseManagerResource>,
+        Write<'s, RunSummaryResource>,
+        ReadExpect<'s, LazyUpdate>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.enemy_destroyed_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<EnemyDestroyedEvent>>()
+                .register_reader(),
+        );
+        self.item_get_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<ItemGetEvent>>()
+                .register_reader(),
+        );
+        self.enemy_reached_bottom_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<EnemyReachedBottomEvent>>()
+                .register_reader(),
+        );
+        self.player_collision_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<PlayerCollisionEvent>>()
+                .register_reader(),
+        );
+        self.entity_damaged_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<EntityDamagedEvent>>()
+                .register_reader(),
+        );
+        self.run_ended_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<RunEndedEvent>>()
+                .register_reader(),
+        );
+        self.font = Some(world.read_resource::<Loader>().load(
+            "font/SpaceMadness.ttf",
+            TtfFormat,
+            (),
+            &world.read_resource(),
+        ));
+    }
+
+    fn run(
+        &mut self,
+        (
+            enemy_destroyed_event_channel,
+            item_get_event_channel,
+            enemy_reached_bottom_event_channel,
+            player_collision_event_channel,
+            entity_damaged_event_channel,
+            run_ended_event_channel,
+            entities,
+            enemies,
+            players,
+            toast_tags,
+            achievements_resource,
+            mut progress,
+            phase_manager,
+            mut run_summary,
+            lazy_update,
+        ): Self::SystemData,
+    ) {
+        let mut destroyed_enemy_types = vec![];
+        for event in
+            enemy_destroyed_event_channel.read(self.enemy_destroyed_event_reader.as_mut().unwrap())
+        {
+            if let Some(enemy) = enemies.get(event.enemy) {
+                if let SpawnableType::Enemy(enemy_type) = &enemy.spawnable_type {
+                    if !enemy.allied {
+                        destroyed_enemy_types.push(enemy_type.clone());
+                    }
+                }
+            }
+        }
+
+        let items_collected = item_get_event_channel
+            .read(self.item_get_event_reader.as_mut().unwrap())
+            .filter(|event| event.item_type.is_some())
+            .count();
+
+        if enemy_reached_bottom_event_channel
+            .read(self.enemy_reached_bottom_event_reader.as_mut().unwrap())
+            .count()
+            > 0
+        {
+            run_summary.defense_breached = true;
+        }
+
+        let mut enemies_rammed = 0;
+        for event in player_collision_event_channel
+            .read(self.player_collision_event_reader.as_mut().unwrap())
+        {
+            if let Some(enemy) = enemies.get(event.colliding_entity) {
+                if !enemy.allied && self.rammed_enemies.insert(event.colliding_entity) {
+                    enemies_rammed += 1;
+                }
+            }
+        }
+        self.rammed_enemies
+            .retain(|rammed_enemy| entities.is_alive(*rammed_enemy));
+
+        for event in
+            entity_damaged_event_channel.read(self.entity_damaged_event_reader.as_mut().unwrap())
+        {
+            if players.get(event.target).is_some() {
+                run_summary.player_damaged = true;
+            }
+        }
+
+        let run_ended_score = run_ended_event_channel
+            .read(self.run_ended_event_reader.as_mut().unwrap())
+            .map(|event| event.score)
+            .max();
+
+        let phase_reached =
+            |phase: usize| phase_manager.loop_count > 0 || phase_manager.phase_idx >= phase;
+
+        let mut toasts_on_screen = (&toast_tags).join().count();
+        let mut achievement_unlocked = false;
+
+        for achievement in achievements_resource.achievements.iter() {
+            if progress.is_unlocked(achievement) {
+                continue;
+            }
+
+            let count_gained = match &achievement.rule {
+                AchievementRule::EnemiesDestroyed(_) => destroyed_enemy_types.len(),
+                AchievementRule::EnemyTypeDestroyed(enemy_type, _) => destroyed_enemy_types
+                    .iter()
+                    .filter(|destroyed_type| *destroyed_type == enemy_type)
+                    .count(),
+                AchievementRule::ItemsCollected(_) => items_collected,
+                AchievementRule::EnemiesRammed(_) => enemies_rammed,
+                _ => 0,
+            };
+
+            progress.add_count(achievement, count_gained);
+
+            let unlocked = match (&achievement.rule, achievement.rule.target_count()) {
+                (_, Some(target_count)) => progress.count(achievement) >= target_count,
+                (AchievementRule::RunScore(score), _) => {
+                    run_ended_score.map_or(false, |run_score| run_score >= *score)
+                }
+                (AchievementRule::PhaseWithoutDamage(phase), _) => {
+                    phase_reached(*phase) && !run_summary.player_damaged
+                }
+                (AchievementRule::PhaseWithoutBreach(phase), _) => {
+                    phase_reached(*phase) && !run_summary.defense_breached
+                }
+                _ => false,
+            };
+
+            if unlocked {
+                progress.unlocked.push(achievement.name.clone());
+                achievement_unlocked = true;
+
+                spawn_toast(
+                    format!("achievement unlocked: {}", achievement.description),
+                    HIGHLIGHT_COLOR,
+                    toasts_on_screen,
+                    self.font.clone().unwrap(),
+                    &entities,
+                    &lazy_update,
+                );
+                toasts_on_screen += 1;
+            }
+        }
+
+        // counts are saved with each unlock and at the end of the run
+        if achievement_unlocked || run_ended_score.is_some() {
+            progress.save();
+        }
+    }
+}
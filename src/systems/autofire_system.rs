@@ -1,14 +1,15 @@
 use crate::{
-    components::{AutoFireComponent, BlasterComponent, Motion2DComponent},
+    components::{AutoFireComponent, BlasterComponent, Motion2DComponent, PlayerComponent},
     resources::SpriteSheetsResource,
 };
 
 use amethyst::{
-    core::{timing::Time, transform::Transform},
+    core::{math::Vector2, timing::Time, transform::Transform},
     ecs::prelude::{
         Entities, Join, LazyUpdate, Read, ReadExpect, ReadStorage, System, WriteStorage,
     },
 };
+use std::cmp::Ordering;
 
 pub struct AutoFireSystem;
 
@@ -21,6 +22,7 @@ impl<'s> System<'s> for AutoFireSystem {
         ReadStorage<'s, BlasterComponent>,
         WriteStorage<'s, AutoFireComponent>,
         ReadStorage<'s, Motion2DComponent>,
+        ReadStorage<'s, PlayerComponent>,
         ReadExpect<'s, SpriteSheetsResource>,
     );
 
@@ -34,9 +36,24 @@ impl<'s> System<'s> for AutoFireSystem {
             blasters,
             mut auto_fires,
             motion2ds,
+            players,
             sprite_resource,
         ): Self::SystemData,
     ) {
+        // positions and velocities of players that can be aimed at
+        let player_targets: Vec<(Vector2<f32>, Vector2<f32>)> = (&players, &transforms, &motion2ds)
+            .join()
+            .map(|(_player, player_transform, player_motion2d)| {
+                (
+                    Vector2::new(
+                        player_transform.translation().x,
+                        player_transform.translation().y,
+                    ),
+                    player_motion2d.velocity,
+                )
+            })
+            .collect();
+
         for (transform, auto_fire, blaster, motion2d) in
             (&transforms, &mut auto_fires, &blasters, &motion2ds).join()
         {
@@ -44,7 +61,25 @@ impl<'s> System<'s> for AutoFireSystem {
                 auto_fire.timer -= time.delta_seconds();
             } else {
                 auto_fire.timer = auto_fire.period;
-                blaster.fire(
+
+                let source_position =
+                    Vector2::new(transform.translation().x, transform.translation().y);
+
+                let closest_target = player_targets
+                    .iter()
+                    .min_by(|(position_a, _), (position_b, _)| {
+                        (position_a - source_position)
+                            .norm()
+                            .partial_cmp(&(position_b - source_position).norm())
+                            .unwrap_or(Ordering::Equal)
+                    })
+                    .cloned();
+
+                let mut aimed_blaster = blaster.clone();
+                aimed_blaster.shot_velocity =
+                    auto_fire.aimed_shot_velocity(blaster, source_position, closest_target);
+
+                aimed_blaster.fire(
                     motion2d,
                     transform,
                     &entities,
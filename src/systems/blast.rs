@@ -0,0 +1,121 @@
+use crate::{
+    components::{
+        BarrierComponent, BlastComponent, BlastType, EnemyComponent, Motion2DComponent,
+        PushDirection,
+    },
+    events::ArenaBorderCollisionEvent,
+};
+use amethyst::{
+    core::{math::Vector2, timing::Time, transform::Transform},
+    ecs::*,
+    shrev::{EventChannel, ReaderId},
+};
+use std::cmp::Ordering;
+
+// steers homing blasts towards the closest enemy
+pub struct BlastHomingSystem;
+
+impl<'s> System<'s> for BlastHomingSystem {
+    type SystemData = (
+        ReadStorage<'s, BlastComponent>,
+        ReadStorage<'s, EnemyComponent>,
+        ReadStorage<'s, Transform>,
+        WriteStorage<'s, Motion2DComponent>,
+        Read<'s, Time>,
+    );
+
+    fn run(&mut self, (blasts, enemies, transforms, mut motion2ds, time): Self::SystemData) {
+        let enemy_positions: Vec<Vector2<f32>> = (&enemies, &transforms)
+            .join()
+            .filter(|(enemy, _)| !enemy.allied)
+            .map(|(_, enemy_transform)| {
+                Vector2::new(
+                    enemy_transform.translation().x,
+                    enemy_transform.translation().y,
+                )
+            })
+            .collect();
+
+        for (blast, transform, motion2d) in (&blasts, &transforms, &mut motion2ds).join() {
+            if blast.homing <= 0.0 {
+                continue;
+            }
+
+            if let BlastType::Enemy = blast.blast_type {
+                continue;
+            }
+
+            let blast_position = Vector2::new(transform.translation().x, transform.translation().y);
+
+            motion2d.target_position = enemy_positions
+                .iter()
+                .min_by(|position_a, position_b| {
+                    (*position_a - blast_position)
+                        .norm()
+                        .partial_cmp(&(*position_b - blast_position).norm())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .cloned();
+
+            motion2d.steer_towards_target(blast_position, blast.homing * time.delta_seconds());
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct BlastArenaBorderCollisionSystem {
+    event_reader: Option<ReaderId<ArenaBorderCollisionEvent>>,
+}
+
+impl<'s> System<'s> for BlastArenaBorderCollisionSystem {
+    type SystemData = (
+        Read<'s, EventChannel<ArenaBorderCollisionEvent>>,
+        ReadStorage<'s, BarrierComponent>,
+        WriteStorage<'s, BlastComponent>,
+        WriteStorage<'s, Motion2DComponent>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<ArenaBorderCollisionEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (collision_event_channel, barriers, mut blasts, mut motion2ds): Self::SystemData,
+    ) {
+        for event in collision_event_channel.read(self.event_reader.as_mut().unwrap()) {
+            // is a bouncing blast colliding with a barrier?
+            if let Some(blast) = blasts.get_mut(event.colliding_entity) {
+                if blast.bounce == 0 {
+                    continue;
+                }
+
+                let barrier = barriers.get(event.arena_border_entity).unwrap();
+                let blast_motion = motion2ds.get_mut(event.colliding_entity).unwrap();
+
+                // only bounce blasts that are still moving into a side barrier, blasts leave
+                // through the top and bottom of the arena
+                let bounced = match barrier.push_direction {
+                    PushDirection::Left if blast_motion.velocity.x > 0.0 => {
+                        blast_motion.velocity.x *= -1.0;
+                        true
+                    }
+                    PushDirection::Right if blast_motion.velocity.x < 0.0 => {
+                        blast_motion.velocity.x *= -1.0;
+                        true
+                    }
+                    _ => false,
+                };
+
+                if bounced {
+                    blast.bounce -= 1;
+                }
+            }
+        }
+    }
+}
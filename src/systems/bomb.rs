@@ -0,0 +1,138 @@
+use crate::{
+    audio::Sounds,
+    components::{BlastComponent, BlastType, DamageKind, EnemyComponent, PlayerComponent},
+    constants::{ARENA_MAX_X, ARENA_MAX_Y, ARENA_MIN_X, ARENA_MIN_Y},
+    entities::{spawn_effect, EffectType},
+    events::{DamageEvent, ItemGetEvent, PlayAudioEvent},
+    resources::{EffectsResource, SpriteSheetsResource},
+};
+use amethyst::{
+    core::transform::Transform,
+    ecs::*,
+    input::{InputHandler, StringBindings},
+    shrev::{EventChannel, ReaderId},
+};
+
+#[derive(Default)]
+pub struct BombSystem {
+    event_reader: Option<ReaderId<ItemGetEvent>>,
+    bomb_action_held: bool,
+}
+
+impl<'s> System<'s> for BombSystem {
+    type SystemData = (
+        Entities<'s>,
+        Read<'s, EventChannel<ItemGetEvent>>,
+        Read<'s, InputHandler<StringBindings>>,
+        WriteStorage<'s, PlayerComponent>,
+        ReadStorage<'s, EnemyComponent>,
+        ReadStorage<'s, BlastComponent>,
+        ReadStorage<'s, Transform>,
+        ReadExpect<'s, EffectsResource>,
+        ReadExpect<'s, SpriteSheetsResource>,
+        ReadExpect<'s, LazyUpdate>,
+        Write<'s, EventChannel<DamageEvent>>,
+        Write<'s, EventChannel<PlayAudioEvent>>,
+        ReadExpect<'s, Sounds>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<ItemGetEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            item_get_event_channel,
+            input,
+            mut players,
+            enemies,
+            blasts,
+            transforms,
+            effects_resource,
+            sprite_resource,
+            lazy_update,
+            mut damage_event_channel,
+            mut play_audio_channel,
+            sounds,
+        ): Self::SystemData,
+    ) {
+        for event in item_get_event_channel.read(self.event_reader.as_mut().unwrap()) {
+            if let Some(player) = players.get_mut(event.player_entity) {
+                if event.stat_effects.contains_key("bomb_capacity") {
+                    let capacity = event.stat_effects["bomb_capacity"] as usize;
+                    player.max_bombs += capacity;
+                    player.add_bombs(capacity);
+                }
+            }
+        }
+
+        // only detonate once per press of the bomb action
+        let bomb_action = input.action_is_down("bomb").unwrap_or(false);
+        let bomb_pressed = bomb_action && !self.bomb_action_held;
+        self.bomb_action_held = bomb_action;
+
+        if !bomb_pressed {
+            return;
+        }
+
+        let (player_entity, bomb_damage) = match (&entities, &mut players)
+            .join()
+            .find(|(_, player)| player.bombs > 0)
+        {
+            Some((player_entity, player)) => {
+                player.bombs -= 1;
+                (player_entity, player.bomb_damage)
+            }
+            None => return,
+        };
+
+        play_audio_channel.single_write(PlayAudioEvent {
+            source: sounds.sound_effects["explosion"].clone(),
+        });
+
+        // clear all enemy blasts from the screen
+        for (blast_entity, blast, blast_transform) in (&entities, &blasts, &transforms).join() {
+            if let BlastType::Enemy = blast.blast_type {
+                entities
+                    .delete(blast_entity)
+                    .expect("unable to delete entity");
+
+                spawn_effect(
+                    &EffectType::EnemyBlastExplosion,
+                    blast_transform.clone(),
+                    &effects_resource,
+                    &sprite_resource,
+                    &entities,
+                    &lazy_update,
+                );
+            }
+        }
+
+        // damage every enemy inside of the arena
+        for (enemy_entity, enemy, enemy_transform) in (&entities, &enemies, &transforms).join() {
+            let translation = enemy_transform.translation();
+
+            if !enemy.allied
+                && translation.x >= ARENA_MIN_X
+                && translation.x <= ARENA_MAX_X
+                && translation.y >= ARENA_MIN_Y
+                && translation.y <= ARENA_MAX_Y
+            {
+                damage_event_channel.single_write(DamageEvent::new(
+                    enemy_entity,
+                    Some(player_entity),
+                    bomb_damage,
+                    DamageKind::Bomb,
+                    false,
+                ));
+            }
+        }
+    }
+}
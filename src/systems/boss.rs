@@ -1,4 +1,7 @@
-use crate::{components::RepeaterComponent, resources::PhaseManagerResource};
+use crate::{
+    components::RepeaterComponent,
+    resources::{PhaseManagerResource, RunSummaryResource},
+};
 use amethyst::ecs::prelude::{Entities, Join, System, Write, WriteStorage};
 
 pub struct BossSystem;
@@ -8,9 +11,13 @@ impl<'s> System<'s> for BossSystem {
         Entities<'s>,
         WriteStorage<'s, RepeaterComponent>,
         Write<'s, PhaseManagerResource>,
+        Write<'s, RunSummaryResource>,
     );
 
-    fn run(&mut self, (entities, mut repeaters, mut phase_managers): Self::SystemData) {
+    fn run(
+        &mut self,
+        (entities, mut repeaters, mut phase_managers, mut run_summary): Self::SystemData,
+    ) {
         for (boss_entity, repeater_component) in (&*entities, &mut repeaters).join() {
             if !entities.is_alive(repeater_component.head)
                 && !entities.is_alive(repeater_component.body)
@@ -19,7 +26,9 @@ impl<'s> System<'s> for BossSystem {
                     .delete(boss_entity)
                     .expect("unable to delete entity");
 
-                phase_managers.phase_idx += 1;
+                // the PhaseManagerSystem moves on once the boss phase's end condition is met
+                phase_managers.boss_defeated = true;
+                run_summary.bosses_defeated += 1;
 
                 println!("repeater defeated");
             }
@@ -0,0 +1,113 @@
+use crate::{
+    components::{
+        BarrelRollAbilityComponent, EnemyComponent, HealthComponent, PowerUpType, PowerUpsComponent,
+    },
+    constants::CRIT_DAMAGE_MULTIPLIER,
+    events::{DamageEvent, EnemyDestroyedEvent, EntityDamagedEvent},
+};
+use amethyst::{
+    ecs::*,
+    shrev::{EventChannel, ReaderId},
+};
+
+#[derive(Default)]
+pub struct DamageSystem {
+    event_reader: Option<ReaderId<DamageEvent>>,
+}
+
+impl<'s> System<'s> for DamageSystem {
+    type SystemData = (
+        Read<'s, EventChannel<DamageEvent>>,
+        Entities<'s>,
+        WriteStorage<'s, HealthComponent>,
+        ReadStorage<'s, EnemyComponent>,
+        ReadStorage<'s, BarrelRollAbilityComponent>,
+        ReadStorage<'s, PowerUpsComponent>,
+        Write<'s, EventChannel<EntityDamagedEvent>>,
+        Write<'s, EventChannel<EnemyDestroyedEvent>>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<DamageEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            damage_event_channel,
+            entities,
+            mut healths,
+            enemies,
+            barrel_roll_abilities,
+            power_ups,
+            mut entity_damaged_event_channel,
+            mut enemy_destroyed_event_channel,
+        ): Self::SystemData,
+    ) {
+        for event in damage_event_channel.read(self.event_reader.as_mut().unwrap()) {
+            if !entities.is_alive(event.target) {
+                continue;
+            }
+
+            let health = match healths.get_mut(event.target) {
+                Some(health) => health,
+                None => continue,
+            };
+
+            // entities that have already been destroyed can't take more damage
+            if health.value <= 0.0 {
+                continue;
+            }
+
+            if let Some(barrel_roll_ability) = barrel_roll_abilities.get(event.target) {
+                if barrel_roll_ability.is_invulnerable_to(&event.kind) {
+                    continue;
+                }
+            }
+
+            if let Some(power_ups) = power_ups.get(event.target) {
+                if power_ups.is_active(&PowerUpType::Invulnerability) {
+                    continue;
+                }
+            }
+
+            if event.kind.blocked_by_armor() && health.armor > 0 {
+                health.armor -= 1;
+                continue;
+            }
+
+            let mut amount = if event.critical {
+                event.amount * CRIT_DAMAGE_MULTIPLIER
+            } else {
+                event.amount
+            };
+
+            amount = (amount * (1.0 - health.resistance(&event.kind, event.critical))).max(0.0);
+
+            // shields absorb damage before health
+            let health_damage = match health.shield.as_mut() {
+                Some(shield) => shield.absorb(amount),
+                None => amount,
+            };
+
+            health.value -= health_damage;
+
+            entity_damaged_event_channel.single_write(EntityDamagedEvent::new(
+                event.target,
+                event.source,
+                amount,
+                event.kind.clone(),
+                event.critical,
+            ));
+
+            if health.value <= 0.0 && enemies.get(event.target).is_some() {
+                enemy_destroyed_event_channel.single_write(EnemyDestroyedEvent::new(event.target));
+            }
+        }
+    }
+}
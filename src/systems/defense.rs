@@ -1,6 +1,7 @@
 use crate::{
-    components::{DefenseTag, HealthComponent},
-    events::{EnemyReachedBottomEvent, ItemGetEvent},
+    components::{DamageKind, DefenseTag, HealthComponent},
+    events::{DamageEvent, EnemyReachedBottomEvent, ItemGetEvent},
+    resources::GameParametersResource,
 };
 use amethyst::{
     ecs::prelude::{Join, ReadStorage, System, WriteStorage},
@@ -18,8 +19,11 @@ impl<'s> System<'s> for DefenseSystem {
     type SystemData = (
         Read<'s, EventChannel<ItemGetEvent>>,
         Read<'s, EventChannel<EnemyReachedBottomEvent>>,
+        Entities<'s>,
         ReadStorage<'s, DefenseTag>,
         WriteStorage<'s, HealthComponent>,
+        Write<'s, EventChannel<DamageEvent>>,
+        Read<'s, GameParametersResource>,
     );
 
     fn setup(&mut self, world: &mut World) {
@@ -41,8 +45,11 @@ impl<'s> System<'s> for DefenseSystem {
         (
             item_get_event_channel,
             enemy_reached_bottom_event_channel,
+            entities,
             defense_tags,
             mut healths,
+            mut damage_event_channel,
+            game_parameters,
         ): Self::SystemData,
     ) {
         for (_defense_tag, health) in (&defense_tags, &mut healths).join() {
@@ -61,8 +68,14 @@ impl<'s> System<'s> for DefenseSystem {
         for event in enemy_reached_bottom_event_channel
             .read(self.enemy_reached_bottom_event_reader.as_mut().unwrap())
         {
-            for (_defense_tag, health) in (&defense_tags, &mut healths).join() {
-                health.value -= event.damage;
+            for (defense_entity, _defense_tag) in (&entities, &defense_tags).join() {
+                damage_event_channel.single_write(DamageEvent::new(
+                    defense_entity,
+                    None,
+                    event.damage * game_parameters.difficulty_preset().defense_damage,
+                    DamageKind::Breach,
+                    false,
+                ));
             }
         }
     }
@@ -0,0 +1,87 @@
+use crate::{
+    components::{
+        BlasterComponent, DifficultyScaledTag, EnemyComponent, HealthComponent, Motion2DComponent,
+    },
+    resources::{GameParametersResource, PhaseManagerResource},
+};
+use amethyst::ecs::*;
+
+// scales newly spawned enemies by the chosen difficulty and the difficulty of the current loop
+pub struct DifficultySystem;
+
+impl<'s> System<'s> for DifficultySystem {
+    type SystemData = (
+        Entities<'s>,
+        WriteStorage<'s, DifficultyScaledTag>,
+        WriteStorage<'s, EnemyComponent>,
+        WriteStorage<'s, HealthComponent>,
+        WriteStorage<'s, Motion2DComponent>,
+        WriteStorage<'s, BlasterComponent>,
+        Read<'s, PhaseManagerResource>,
+        Read<'s, GameParametersResource>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            mut difficulty_scaled_tags,
+            mut enemies,
+            mut healths,
+            mut motion_2ds,
+            mut blasters,
+            phase_manager,
+            game_parameters,
+        ): Self::SystemData,
+    ) {
+        let difficulty = game_parameters.difficulty(phase_manager.loop_count);
+        let difficulty_preset = game_parameters.difficulty_preset();
+        let health_multiplier = difficulty.health * difficulty_preset.enemy_health;
+        let damage_multiplier = difficulty.damage * difficulty_preset.enemy_damage;
+        let mut scaled_entities = vec![];
+
+        for (entity, enemy, _) in (&entities, &mut enemies, !&difficulty_scaled_tags).join() {
+            scaled_entities.push(entity);
+
+            // allies aren't made any stronger against the player
+            if enemy.allied {
+                continue;
+            }
+
+            enemy.defense_damage *= difficulty.damage; // the chosen difficulty scales defense damage when it is dealt
+            enemy.collision_damage *= damage_multiplier;
+
+            for (loot, probability) in enemy.loot_probs.iter_mut() {
+                if loot.is_some() {
+                    *probability *= difficulty.loot;
+                }
+            }
+
+            if let Some(health) = healths.get_mut(entity) {
+                health.max_value *= health_multiplier;
+                health.value *= health_multiplier;
+
+                if let Some(shield) = health.shield.as_mut() {
+                    shield.max_value *= health_multiplier;
+                    shield.value *= health_multiplier;
+                }
+            }
+
+            if let Some(motion_2d) = motion_2ds.get_mut(entity) {
+                motion_2d.speed *= difficulty.speed;
+                motion_2d.max_speed *= difficulty.speed;
+            }
+
+            if let Some(blaster) = blasters.get_mut(entity) {
+                blaster.damage *= damage_multiplier;
+                blaster.poison_damage *= damage_multiplier;
+            }
+        }
+
+        for entity in scaled_entities {
+            difficulty_scaled_tags
+                .insert(entity, DifficultyScaledTag)
+                .expect("unable to insert difficulty scaled tag");
+        }
+    }
+}
@@ -1,18 +1,19 @@
 use crate::{
     audio::Sounds,
     components::{
-        BarrierComponent, BlastComponent, BlastType, EnemyComponent, HealthComponent,
-        Motion2DComponent, PlayerComponent,
+        BarrierComponent, BlastComponent, BlastType, DamageKind, EnemyComponent, HealthComponent,
+        Hitbox2DComponent, Motion2DComponent, PlayerComponent, PoisonComponent,
     },
-    entities::{spawn_effect, EffectType, EnemyType, SpawnableType},
-    events::{EnemyCollisionEvent, PlayAudioEvent},
-    resources::{EffectsResource, GameParametersResource, SpriteSheetsResource},
+    entities::{spawn_blast_fragments, spawn_effect, EffectType, EnemyType, SpawnableType},
+    events::{DamageEvent, EnemyCollisionEvent, PlayAudioEvent},
+    resources::{EffectsResource, GameParametersResource, RunStatsResource, SpriteSheetsResource},
     systems::{barrier_collision, immovable_collision, standard_collision},
 };
 use amethyst::{
     core::transform::Transform,
     ecs::*,
     ecs::{Read, System, World},
+    renderer::SpriteRender,
     shrev::{EventChannel, ReaderId},
 };
 
@@ -29,8 +30,14 @@ impl<'s> System<'s> for EnemyPlayerCollisionSystem {
         WriteStorage<'s, EnemyComponent>,
         WriteStorage<'s, Motion2DComponent>,
         WriteStorage<'s, HealthComponent>,
+        ReadStorage<'s, Transform>,
+        Write<'s, EventChannel<DamageEvent>>,
         Write<'s, EventChannel<PlayAudioEvent>>,
         ReadExpect<'s, Sounds>,
+        Entities<'s>,
+        ReadExpect<'s, EffectsResource>,
+        ReadExpect<'s, SpriteSheetsResource>,
+        ReadExpect<'s, LazyUpdate>,
     );
 
     fn setup(&mut self, world: &mut World) {
@@ -51,8 +58,14 @@ impl<'s> System<'s> for EnemyPlayerCollisionSystem {
             mut enemies,
             mut motions,
             mut healths,
+            transforms,
+            mut damage_event_channel,
             mut play_audio_channel,
             sounds,
+            entities,
+            effects_resource,
+            sprite_resource,
+            lazy_update,
         ): Self::SystemData,
     ) {
         for event in enemy_collision_event_channel.read(self.event_reader.as_mut().unwrap()) {
@@ -68,12 +81,27 @@ impl<'s> System<'s> for EnemyPlayerCollisionSystem {
 
                 match enemy.spawnable_type {
                     SpawnableType::Enemy(EnemyType::Missile) => {
-                        enemy_health.value = 0.0;
-                    }
-
-                    _ => {
-                        enemy_health.value -= player.collision_damage;
+                        if detonate_missile(
+                            event.enemy_entity,
+                            enemy_health,
+                            transforms.get(event.enemy_entity).unwrap(),
+                            &entities,
+                            &effects_resource,
+                            &sprite_resource,
+                            &lazy_update,
+                        ) {
+                            play_audio_channel.single_write(PlayAudioEvent {
+                                source: sounds.sound_effects["explosion"].clone(),
+                            });
+                        }
                     }
+                    _ => damage_event_channel.single_write(DamageEvent::new(
+                        event.enemy_entity,
+                        Some(event.colliding_entity),
+                        player.collision_damage,
+                        DamageKind::Collision,
+                        false,
+                    )),
                 }
 
                 if !enemy_motion.immovable {
@@ -102,8 +130,14 @@ impl<'s> System<'s> for EnemyEnemyCollisionSystem {
         ReadStorage<'s, EnemyComponent>,
         WriteStorage<'s, Motion2DComponent>,
         WriteStorage<'s, HealthComponent>,
+        ReadStorage<'s, Transform>,
+        Write<'s, EventChannel<DamageEvent>>,
         Write<'s, EventChannel<PlayAudioEvent>>,
         ReadExpect<'s, Sounds>,
+        Entities<'s>,
+        ReadExpect<'s, EffectsResource>,
+        ReadExpect<'s, SpriteSheetsResource>,
+        ReadExpect<'s, LazyUpdate>,
     );
 
     fn setup(&mut self, world: &mut World) {
@@ -123,8 +157,14 @@ impl<'s> System<'s> for EnemyEnemyCollisionSystem {
             enemies,
             mut motions,
             mut healths,
+            transforms,
+            mut damage_event_channel,
             mut play_audio_channel,
             sounds,
+            entities,
+            effects_resource,
+            sprite_resource,
+            lazy_update,
         ): Self::SystemData,
     ) {
         for event in enemy_collision_event_channel.read(self.event_reader.as_mut().unwrap()) {
@@ -138,12 +178,27 @@ impl<'s> System<'s> for EnemyEnemyCollisionSystem {
 
                 match enemy.spawnable_type {
                     SpawnableType::Enemy(EnemyType::Missile) => {
-                        enemy_health.value = 0.0;
-                    }
-
-                    _ => {
-                        enemy_health.value -= colliding_enemy.collision_damage;
+                        if detonate_missile(
+                            event.enemy_entity,
+                            enemy_health,
+                            transforms.get(event.enemy_entity).unwrap(),
+                            &entities,
+                            &effects_resource,
+                            &sprite_resource,
+                            &lazy_update,
+                        ) {
+                            play_audio_channel.single_write(PlayAudioEvent {
+                                source: sounds.sound_effects["explosion"].clone(),
+                            });
+                        }
                     }
+                    _ => damage_event_channel.single_write(DamageEvent::new(
+                        event.enemy_entity,
+                        Some(event.colliding_entity),
+                        colliding_enemy.collision_damage,
+                        DamageKind::Collision,
+                        false,
+                    )),
                 }
 
                 if !enemy_motion.immovable {
@@ -177,14 +232,19 @@ impl<'s> System<'s> for EnemyBlastCollisionSystem {
     type SystemData = (
         Read<'s, EventChannel<EnemyCollisionEvent>>,
         Entities<'s>,
-        WriteStorage<'s, HealthComponent>,
         WriteStorage<'s, BlastComponent>,
+        WriteStorage<'s, PoisonComponent>,
         ReadStorage<'s, Transform>,
+        ReadStorage<'s, Motion2DComponent>,
+        ReadStorage<'s, Hitbox2DComponent>,
+        ReadStorage<'s, SpriteRender>,
         ReadExpect<'s, EffectsResource>,
         ReadExpect<'s, SpriteSheetsResource>,
         ReadExpect<'s, LazyUpdate>,
+        Write<'s, EventChannel<DamageEvent>>,
         Write<'s, EventChannel<PlayAudioEvent>>,
         ReadExpect<'s, Sounds>,
+        Write<'s, RunStatsResource>,
     );
 
     fn setup(&mut self, world: &mut World) {
@@ -201,26 +261,39 @@ impl<'s> System<'s> for EnemyBlastCollisionSystem {
         (
             collision_channel,
             entities,
-            mut healths,
             mut blasts,
+            mut poisons,
             transforms,
+            motion2ds,
+            hitbox2ds,
+            sprite_renders,
             effects_resource,
             sprite_resource,
             lazy_update,
+            mut damage_event_channel,
             mut play_audio_channel,
             sounds,
+            mut run_stats,
         ): Self::SystemData,
     ) {
         for event in collision_channel.read(self.event_reader.as_mut().unwrap()) {
+            let mut blast_destroyed = false;
+
             if let Some(blast) = blasts.get_mut(event.colliding_entity) {
-                let enemy_health = healths.get_mut(event.enemy_entity).unwrap();
                 let blast_transform = transforms.get(event.colliding_entity).unwrap();
 
                 match blast.blast_type {
                     BlastType::Ally | BlastType::AllyCritical | BlastType::AllyPoison => {
-                        entities
-                            .delete(event.colliding_entity)
-                            .expect("unable to delete entity");
+                        // blasts passing through an enemy only damage it once
+                        if blast.hit_entities.contains(&event.enemy_entity) {
+                            continue;
+                        }
+
+                        // piercing blasts count as one hit for accuracy
+                        if blast.hit_entities.is_empty() {
+                            run_stats.blasts_hit += 1;
+                        }
+                        blast.hit_entities.push(event.enemy_entity);
 
                         play_audio_channel.single_write(PlayAudioEvent {
                             source: sounds.sound_effects["metal_ping"].clone(),
@@ -242,14 +315,59 @@ impl<'s> System<'s> for EnemyBlastCollisionSystem {
                             &lazy_update,
                         );
 
-                        enemy_health.value -= blast.damage;
-                        //TODO: apply poison to enemy health component from blast
-                        //enemy.poison = blast.poison_damage;
+                        damage_event_channel.single_write(DamageEvent::new(
+                            event.enemy_entity,
+                            Some(event.colliding_entity),
+                            blast.damage,
+                            DamageKind::Blast,
+                            if let BlastType::AllyCritical = blast.blast_type {
+                                true
+                            } else {
+                                false
+                            },
+                        ));
+
+                        if blast.poison_damage > 0.0 {
+                            run_stats.poisons += 1;
+                            poisons
+                                .insert(
+                                    event.enemy_entity,
+                                    PoisonComponent::new(blast.poison_damage),
+                                )
+                                .expect("unable to insert poison");
+                        }
+
+                        if blast.pierce > 0 {
+                            blast.pierce -= 1;
+                        } else {
+                            entities
+                                .delete(event.colliding_entity)
+                                .expect("unable to delete entity");
+
+                            if blast.split > 0 {
+                                spawn_blast_fragments(
+                                    blast,
+                                    sprite_renders.get(event.colliding_entity).unwrap().clone(),
+                                    hitbox2ds.get(event.colliding_entity).unwrap().clone(),
+                                    motion2ds.get(event.colliding_entity).unwrap().clone(),
+                                    blast_transform.clone(),
+                                    &entities,
+                                    &lazy_update,
+                                );
+                            }
+
+                            blast_destroyed = true;
+                        }
                     }
 
                     _ => {}
                 }
             }
+
+            // prevent destroyed blasts from hitting other enemies before they are removed
+            if blast_destroyed {
+                blasts.remove(event.colliding_entity);
+            }
         }
     }
 }
@@ -265,7 +383,7 @@ impl<'s> System<'s> for EnemyArenaBorderCollisionSystem {
         ReadStorage<'s, BarrierComponent>,
         ReadStorage<'s, EnemyComponent>,
         WriteStorage<'s, Motion2DComponent>,
-        WriteStorage<'s, HealthComponent>,
+        Write<'s, EventChannel<DamageEvent>>,
         Write<'s, EventChannel<PlayAudioEvent>>,
         ReadExpect<'s, Sounds>,
     );
@@ -286,7 +404,7 @@ impl<'s> System<'s> for EnemyArenaBorderCollisionSystem {
             barriers,
             enemies,
             mut motion_2ds,
-            mut healths,
+            mut damage_event_channel,
             mut play_audio_channel,
             sounds,
         ): Self::SystemData,
@@ -302,11 +420,16 @@ impl<'s> System<'s> for EnemyArenaBorderCollisionSystem {
 
                         _ => {
                             let enemy_motion = motion_2ds.get_mut(event.enemy_entity).unwrap();
-                            let enemy_health = healths.get_mut(event.enemy_entity).unwrap();
 
                             barrier_collision(enemy_motion, barrier);
 
-                            enemy_health.value -= barrier.damage;
+                            damage_event_channel.single_write(DamageEvent::new(
+                                event.enemy_entity,
+                                Some(event.colliding_entity),
+                                barrier.damage,
+                                DamageKind::Barrier,
+                                false,
+                            ));
 
                             play_audio_channel.single_write(PlayAudioEvent {
                                 source: sounds.sound_effects["force_field"].clone(),
@@ -318,3 +441,33 @@ impl<'s> System<'s> for EnemyArenaBorderCollisionSystem {
         }
     }
 }
+
+// missiles detonate on contact and are removed without counting as a kill, returns false if the
+// missile already detonated this frame
+fn detonate_missile(
+    missile_entity: Entity,
+    missile_health: &mut HealthComponent,
+    missile_transform: &Transform,
+    entities: &Entities,
+    effects_resource: &ReadExpect<EffectsResource>,
+    sprite_resource: &ReadExpect<SpriteSheetsResource>,
+    lazy_update: &ReadExpect<LazyUpdate>,
+) -> bool {
+    if missile_health.value <= 0.0 {
+        return false;
+    }
+
+    missile_health.value = 0.0;
+    spawn_effect(
+        &EffectType::EnemyExplosion,
+        missile_transform.clone(),
+        effects_resource,
+        sprite_resource,
+        entities,
+        lazy_update,
+    );
+    entities
+        .delete(missile_entity)
+        .expect("unable to delete entity");
+    true
+}
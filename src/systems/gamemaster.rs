@@ -1,29 +1,83 @@
-use crate::resources::{PhaseManagerResource, PhaseType};
+use crate::{
+    components::{DefenseTag, EnemyComponent, HealthComponent},
+    events::{EnemyDestroyedEvent, PhaseChangedEvent},
+    resources::{PhaseManagerResource, RunSummaryResource},
+};
 use amethyst::{
     core::timing::Time,
-    ecs::prelude::{Read, System, Write},
+    ecs::*,
+    shrev::{EventChannel, ReaderId},
 };
 
-pub struct PhaseManagerSystem;
+#[derive(Default)]
+pub struct PhaseManagerSystem {
+    event_reader: Option<ReaderId<EnemyDestroyedEvent>>,
+}
 
 impl<'s> System<'s> for PhaseManagerSystem {
-    type SystemData = (Write<'s, PhaseManagerResource>, Read<'s, Time>);
+    type SystemData = (
+        Read<'s, EventChannel<EnemyDestroyedEvent>>,
+        ReadStorage<'s, EnemyComponent>,
+        ReadStorage<'s, DefenseTag>,
+        ReadStorage<'s, HealthComponent>,
+        Write<'s, PhaseManagerResource>,
+        Write<'s, EventChannel<PhaseChangedEvent>>,
+        Read<'s, RunSummaryResource>,
+        Read<'s, Time>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<EnemyDestroyedEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            enemy_destroyed_event_channel,
+            enemies,
+            defense_tags,
+            healths,
+            mut phase_manager,
+            mut phase_changed_event_channel,
+            run_summary,
+            time,
+        ): Self::SystemData,
+    ) {
+        let enemies_killed = enemy_destroyed_event_channel
+            .read(self.event_reader.as_mut().unwrap())
+            .count();
+        phase_manager.enemies_killed += enemies_killed;
+
+        let enemies_alive = enemies.join().filter(|enemy| !enemy.allied).count();
 
-    fn run(&mut self, (mut phase_manager, time): Self::SystemData) {
-        match phase_manager.phase_map[phase_manager.phase_idx].phase_type {
-            PhaseType::RandomInvasion => {
-                phase_manager.update(time.delta_seconds());
-            }
+        let defense_health = match (&defense_tags, &healths).join().next() {
+            Some((_, defense_health)) => defense_health.value,
+            None => 0.0,
+        };
 
-            PhaseType::FormationInvasion => {
-                phase_manager.update(time.delta_seconds());
-            }
+        let previous_phase = phase_manager.phase_idx;
 
-            PhaseType::Rest => {
-                phase_manager.update(time.delta_seconds());
-            }
+        if phase_manager.update(
+            time.delta_seconds(),
+            enemies_alive,
+            defense_health,
+            run_summary.endless_mode,
+        ) {
+            let phase_type = phase_manager.phase_map[phase_manager.phase_idx]
+                .phase_type
+                .clone();
 
-            PhaseType::Boss => {}
+            phase_changed_event_channel.single_write(PhaseChangedEvent::new(
+                previous_phase,
+                phase_manager.phase_idx,
+                phase_type,
+                phase_manager.loop_count,
+            ));
         }
     }
 }
@@ -0,0 +1,209 @@
+use crate::{
+    components::{DamageNumberTag, EnemyComponent, HitFlashComponent, PlayerComponent},
+    constants::{
+        DAMAGE_NUMBER_OFFSET_Y, DAMAGE_NUMBER_SPREAD_X, HIT_FLASH_DURATION, HIT_FLASH_INTENSITY,
+    },
+    entities::spawn_damage_number,
+    events::EntityDamagedEvent,
+};
+use amethyst::{
+    assets::Loader,
+    core::{
+        math::{Point3, Vector2, Vector3},
+        timing::Time,
+        transform::Transform,
+    },
+    ecs::*,
+    renderer::{palette::Srgba, resources::Tint, Camera},
+    shrev::{EventChannel, ReaderId},
+    ui::{FontHandle, TtfFormat, UiText, UiTransform},
+    window::ScreenDimensions,
+};
+use rand::{thread_rng, Rng};
+
+#[derive(Default)]
+pub struct HitFeedbackSystem {
+    event_reader: Option<ReaderId<EntityDamagedEvent>>,
+    font: Option<FontHandle>,
+}
+
+impl<'s> System<'s> for HitFeedbackSystem {
+    type SystemData = (
+        Read<'s, EventChannel<EntityDamagedEvent>>,
+        Entities<'s>,
+        ReadStorage<'s, EnemyComponent>,
+        ReadStorage<'s, PlayerComponent>,
+        ReadStorage<'s, Transform>,
+        WriteStorage<'s, Tint>,
+        WriteStorage<'s, HitFlashComponent>,
+        ReadExpect<'s, LazyUpdate>,
+        Read<'s, Time>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<EntityDamagedEvent>>()
+                .register_reader(),
+        );
+        self.font = Some(world.read_resource::<Loader>().load(
+            "font/SpaceMadness.ttf",
+            TtfFormat,
+            (),
+            &world.read_resource(),
+        ));
+    }
+
+    fn run(
+        &mut self,
+        (
+            entity_damaged_event_channel,
+            entities,
+            enemies,
+            players,
+            transforms,
+            mut tints,
+            mut hit_flashes,
+            lazy_update,
+            time,
+        ): Self::SystemData,
+    ) {
+        // restore the tints of entities that have finished flashing
+        let mut finished_flashes = vec![];
+        for (entity, hit_flash) in (&entities, &mut hit_flashes).join() {
+            hit_flash.timer -= time.delta_seconds();
+
+            if hit_flash.timer <= 0.0 {
+                finished_flashes.push(entity);
+            }
+        }
+
+        for entity in finished_flashes {
+            if let Some(hit_flash) = hit_flashes.remove(entity) {
+                match hit_flash.original_tint {
+                    Some(original_tint) => {
+                        tints
+                            .insert(entity, original_tint)
+                            .expect("unable to insert tint");
+                    }
+                    None => {
+                        tints.remove(entity);
+                    }
+                }
+            }
+        }
+
+        for event in entity_damaged_event_channel.read(self.event_reader.as_mut().unwrap()) {
+            if !entities.is_alive(event.target)
+                || (enemies.get(event.target).is_none() && players.get(event.target).is_none())
+            {
+                continue;
+            }
+
+            if let Some(target_transform) = transforms.get(event.target) {
+                let spawn_position = Vector3::new(
+                    target_transform.translation().x
+                        + thread_rng().gen_range(-DAMAGE_NUMBER_SPREAD_X, DAMAGE_NUMBER_SPREAD_X),
+                    target_transform.translation().y + DAMAGE_NUMBER_OFFSET_Y,
+                    target_transform.translation().z,
+                );
+
+                spawn_damage_number(
+                    event,
+                    spawn_position,
+                    self.font.clone().unwrap(),
+                    &entities,
+                    &lazy_update,
+                );
+            }
+
+            // keep the tint from before the first hit if the entity is already flashing
+            match hit_flashes.get_mut(event.target) {
+                Some(hit_flash) => hit_flash.timer = HIT_FLASH_DURATION,
+                None => {
+                    let original_tint = tints.get(event.target).cloned();
+                    hit_flashes
+                        .insert(
+                            event.target,
+                            HitFlashComponent {
+                                timer: HIT_FLASH_DURATION,
+                                original_tint,
+                            },
+                        )
+                        .expect("unable to insert hit flash");
+                }
+            }
+
+            tints
+                .insert(
+                    event.target,
+                    Tint(Srgba::new(
+                        HIT_FLASH_INTENSITY,
+                        HIT_FLASH_INTENSITY,
+                        HIT_FLASH_INTENSITY,
+                        1.0,
+                    )),
+                )
+                .expect("unable to insert tint");
+        }
+    }
+}
+
+// keeps damage numbers' ui text over their position in the arena
+pub struct DamageNumberSystem;
+
+impl<'s> System<'s> for DamageNumberSystem {
+    type SystemData = (
+        ReadStorage<'s, DamageNumberTag>,
+        ReadStorage<'s, Camera>,
+        ReadStorage<'s, Transform>,
+        ReadStorage<'s, Tint>,
+        WriteStorage<'s, UiTransform>,
+        WriteStorage<'s, UiText>,
+        ReadExpect<'s, ScreenDimensions>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            damage_number_tags,
+            cameras,
+            transforms,
+            tints,
+            mut ui_transforms,
+            mut ui_texts,
+            screen_dimensions,
+        ): Self::SystemData,
+    ) {
+        let (camera, camera_transform) = match (&cameras, &transforms).join().next() {
+            Some((camera, camera_transform)) => (camera.clone(), camera_transform.clone()),
+            None => return,
+        };
+
+        let screen_diagonal = Vector2::new(screen_dimensions.width(), screen_dimensions.height());
+
+        for (_damage_number_tag, transform, tint, ui_transform, ui_text) in (
+            &damage_number_tags,
+            &transforms,
+            &tints,
+            &mut ui_transforms,
+            &mut ui_texts,
+        )
+            .join()
+        {
+            let screen_position = camera.world_to_screen(
+                Point3::from(*transform.translation()),
+                screen_diagonal,
+                &camera_transform,
+            );
+
+            // screen coordinates start at the top of the window while ui coordinates start at the bottom
+            ui_transform.local_x = screen_position.x;
+            ui_transform.local_y = screen_dimensions.height() - screen_position.y;
+
+            // FadeSystem fades the tint, which is mirrored onto the text
+            ui_text.color = [tint.0.red, tint.0.green, tint.0.blue, tint.0.alpha];
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    audio::Sounds,
+    components::{InventoryComponent, ToastTag},
+    constants::SYNERGY_COLOR,
+    entities::{spawn_inventory_icon, spawn_synergy_label, spawn_toast},
+    events::{ItemGetEvent, PlayAudioEvent},
+    resources::{ItemsResource, SpriteSheetsResource, SynergiesResource},
+};
+use amethyst::{
+    assets::Loader,
+    ecs::*,
+    shrev::EventChannel,
+    ui::{FontHandle, TtfFormat},
+};
+
+// activates synergies once the player owns all of their items
+#[derive(Default)]
+pub struct SynergySystem {
+    font: Option<FontHandle>,
+}
+
+impl<'s> System<'s> for SynergySystem {
+    type SystemData = (
+        Entities<'s>,
+        WriteStorage<'s, InventoryComponent>,
+        ReadStorage<'s, ToastTag>,
+        ReadExpect<'s, SynergiesResource>,
+        ReadExpect<'s, LazyUpdate>,
+        Write<'s, EventChannel<ItemGetEvent>>,
+        Write<'s, EventChannel<PlayAudioEvent>>,
+        ReadExpect<'s, Sounds>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.font = Some(load_font(world));
+    }
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            mut inventories,
+            toast_tags,
+            synergies_resource,
+            lazy_update,
+            mut item_get_event_channel,
+            mut play_audio_channel,
+            sounds,
+        ): Self::SystemData,
+    ) {
+        let mut toasts_on_screen = (&toast_tags).join().count();
+
+        for (player_entity, inventory) in (&entities, &mut inventories).join() {
+            for synergy in synergies_resource.synergies.iter() {
+                if inventory.synergies.contains(&synergy.name) || !inventory.has_all(&synergy.items)
+                {
+                    continue;
+                }
+
+                inventory.synergies.push(synergy.name.clone());
+
+                // synergy effects are applied the same way as an item's
+                item_get_event_channel.single_write(ItemGetEvent::new(
+                    player_entity,
+                    synergy.stat_effects.clone(),
+                    synergy.bool_effects.clone(),
+                    None,
+                ));
+
+                play_audio_channel.single_write(PlayAudioEvent {
+                    source: sounds.sound_effects["shotgun_cock"].clone(),
+                });
+
+                spawn_toast(
+                    format!("synergy: {}", synergy.description),
+                    SYNERGY_COLOR,
+                    toasts_on_screen,
+                    self.font.clone().unwrap(),
+                    &entities,
+                    &lazy_update,
+                );
+                toasts_on_screen += 1;
+            }
+        }
+    }
+}
+
+// adds an icon to the side panel for each new item type in the player's inventory and a label
+// for each new synergy
+#[derive(Default)]
+pub struct InventoryDisplaySystem {
+    displayed_items: usize,
+    displayed_synergies: usize,
+    font: Option<FontHandle>,
+}
+
+impl<'s> System<'s> for InventoryDisplaySystem {
+    type SystemData = (
+        Entities<'s>,
+        ReadStorage<'s, InventoryComponent>,
+        ReadExpect<'s, ItemsResource>,
+        ReadExpect<'s, SpriteSheetsResource>,
+        ReadExpect<'s, LazyUpdate>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.font = Some(load_font(world));
+    }
+
+    fn run(
+        &mut self,
+        (entities, inventories, items_resource, spritesheets_resource, lazy_update): Self::SystemData,
+    ) {
+        for inventory in (&inventories).join() {
+            for (inventory_index, (item_type, _)) in inventory
+                .items
+                .iter()
+                .enumerate()
+                .skip(self.displayed_items)
+            {
+                spawn_inventory_icon(
+                    item_type,
+                    inventory_index,
+                    &items_resource,
+                    &spritesheets_resource,
+                    &entities,
+                    &lazy_update,
+                );
+            }
+
+            for (synergy_index, synergy_name) in inventory
+                .synergies
+                .iter()
+                .enumerate()
+                .skip(self.displayed_synergies)
+            {
+                spawn_synergy_label(
+                    synergy_name,
+                    synergy_index,
+                    self.font.clone().unwrap(),
+                    &entities,
+                    &lazy_update,
+                );
+            }
+
+            self.displayed_items = self.displayed_items.max(inventory.items.len());
+            self.displayed_synergies = self.displayed_synergies.max(inventory.synergies.len());
+        }
+    }
+}
+
+fn load_font(world: &World) -> FontHandle {
+    world.read_resource::<Loader>().load(
+        "font/SpaceMadness.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    )
+}
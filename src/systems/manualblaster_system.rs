@@ -1,23 +1,32 @@
-use crate::components::ManualFireComponent;
+use crate::components::{ManualFireComponent, PowerUpsComponent, SecondaryFireComponent};
 
 use amethyst::{
     core::timing::Time,
-    ecs::prelude::{Join, Read, System, WriteStorage},
+    ecs::prelude::{Join, Read, ReadStorage, System, WriteStorage},
 };
 
 pub struct ManualBlasterSystem;
 
 impl<'s> System<'s> for ManualBlasterSystem {
-    type SystemData = (Read<'s, Time>, WriteStorage<'s, ManualFireComponent>);
+    type SystemData = (
+        Read<'s, Time>,
+        WriteStorage<'s, ManualFireComponent>,
+        WriteStorage<'s, SecondaryFireComponent>,
+        ReadStorage<'s, PowerUpsComponent>,
+    );
 
-    fn run(&mut self, (time, mut manual_fires): Self::SystemData) {
-        for manual_fire in (&mut manual_fires).join() {
-            if manual_fire.timer > 0.0 && !manual_fire.ready {
-                manual_fire.timer -= time.delta_seconds();
-            } else if !manual_fire.ready {
-                manual_fire.timer = manual_fire.period;
-                manual_fire.ready = true;
-            }
+    fn run(&mut self, (time, mut manual_fires, mut secondary_fires, power_ups): Self::SystemData) {
+        // rapid fire counts down to the next blast faster
+        for (manual_fire, power_ups) in (&mut manual_fires, power_ups.maybe()).join() {
+            let fire_rate_multiplier = power_ups.map_or(1.0, |p| p.fire_rate_multiplier());
+            manual_fire.update(time.delta_seconds() * fire_rate_multiplier);
+        }
+
+        for (secondary_fire, power_ups) in (&mut secondary_fires, power_ups.maybe()).join() {
+            let fire_rate_multiplier = power_ups.map_or(1.0, |p| p.fire_rate_multiplier());
+            secondary_fire
+                .manual_fire
+                .update(time.delta_seconds() * fire_rate_multiplier);
         }
     }
 }
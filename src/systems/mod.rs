@@ -2,22 +2,35 @@ use crate::components::{BarrierComponent, Motion2DComponent, PushDirection};
 use amethyst::core::math::Vector2;
 
 mod ability;
+mod achievements;
 mod animation;
 mod autofire_system;
+mod blast;
+mod bomb;
 mod boss;
 mod child_spawner;
 mod collision_detection;
+mod damage;
 mod defense;
 mod despawn;
+mod difficulty;
 mod enemy_behavior;
 mod enemy_collisions;
 mod enemy_destroyed;
 mod fade;
 mod gamemaster;
+mod hit_feedback;
+mod inventory;
 mod manualblaster_system;
 mod motion2d;
+mod pickup_magnet;
 mod planets;
 mod play_audio;
+mod poison;
+mod power_up;
+mod run_stats;
+mod score;
+mod shield;
 mod spaceship;
 mod spaceship_collisions;
 mod spaceship_movement;
@@ -29,13 +42,18 @@ mod timelimit;
 
 pub use self::{
     ability::BarrelRollAbilitySystem,
+    achievements::AchievementSystem,
     animation::AnimationSystem,
     autofire_system::AutoFireSystem,
+    blast::{BlastArenaBorderCollisionSystem, BlastHomingSystem},
+    bomb::BombSystem,
     boss::BossSystem,
     child_spawner::AutoSpawnerSystem,
     collision_detection::{CollisionDetectionSystem, CollisionHandlerSystem},
+    damage::DamageSystem,
     defense::DefenseSystem,
     despawn::DespawnAtBorderSystem,
+    difficulty::DifficultySystem,
     enemy_behavior::EnemyBehaviorSystem,
     enemy_collisions::{
         EnemyArenaBorderCollisionSystem, EnemyBlastCollisionSystem, EnemyEnemyCollisionSystem,
@@ -44,10 +62,18 @@ pub use self::{
     enemy_destroyed::EnemyDestroyedSystem,
     fade::FadeSystem,
     gamemaster::PhaseManagerSystem,
+    hit_feedback::{DamageNumberSystem, HitFeedbackSystem},
+    inventory::{InventoryDisplaySystem, SynergySystem},
     manualblaster_system::ManualBlasterSystem,
-    motion2d::{EnemyMotion2DSystem, EnemyTargetSystem, Motion2DSystem},
+    motion2d::{EnemyMotion2DSystem, EnemyTargetSystem, Motion2DSystem, PathFollowSystem},
+    pickup_magnet::PickupMagnetSystem,
     planets::PlanetsSystem,
     play_audio::PlayAudioSystem,
+    poison::PoisonSystem,
+    power_up::PowerUpSystem,
+    run_stats::RunStatsSystem,
+    score::ScoreSystem,
+    shield::ShieldSystem,
     spaceship::SpaceshipSystem,
     spaceship_collisions::{
         SpaceshipArenaBorderCollisionSystem, SpaceshipBlastCollisionSystem,
@@ -1,11 +1,14 @@
 use crate::{
-    components::{EnemyComponent, Hitbox2DComponent, Motion2DComponent, PlayerComponent},
+    components::{
+        EnemyComponent, Hitbox2DComponent, Motion2DComponent, PathFollowerComponent,
+        PlayerComponent, PowerUpsComponent,
+    },
     constants::{ARENA_HEIGHT, ARENA_MIN_Y},
     entities::{EnemyType, SpawnableType},
 };
 use amethyst::{
     core::{math::Vector2, timing::Time, transform::Transform},
-    ecs::prelude::{Join, Read, ReadStorage, System, WriteStorage},
+    ecs::prelude::{Entities, Join, Read, ReadStorage, System, WriteStorage},
 };
 
 // basic physics for all Motion2D entities
@@ -15,12 +18,29 @@ impl<'s> System<'s> for Motion2DSystem {
     type SystemData = (
         WriteStorage<'s, Motion2DComponent>,
         WriteStorage<'s, Transform>,
+        ReadStorage<'s, EnemyComponent>,
+        ReadStorage<'s, PowerUpsComponent>,
         Read<'s, Time>,
     );
 
-    fn run(&mut self, (mut motion_2ds, mut transforms, time): Self::SystemData) {
-        for (motion_2d, transform) in (&mut motion_2ds, &mut transforms).join() {
-            let dt = time.delta_seconds();
+    fn run(
+        &mut self,
+        (mut motion_2ds, mut transforms, enemies, power_ups, time): Self::SystemData,
+    ) {
+        // enemies are slowed down while any player has the slow enemies power-up
+        let enemy_time_scale = (&power_ups)
+            .join()
+            .map(|power_ups| power_ups.enemy_time_scale())
+            .fold(1.0, f32::min);
+
+        for (motion_2d, transform, enemy) in
+            (&mut motion_2ds, &mut transforms, enemies.maybe()).join()
+        {
+            let dt = if enemy.is_some() {
+                time.delta_seconds() * enemy_time_scale
+            } else {
+                time.delta_seconds()
+            };
 
             // update translation based on velocity and delta time
             transform.set_translation_xyz(
@@ -68,20 +88,80 @@ pub struct EnemyMotion2DSystem;
 impl<'s> System<'s> for EnemyMotion2DSystem {
     type SystemData = (
         ReadStorage<'s, EnemyComponent>,
+        ReadStorage<'s, PathFollowerComponent>,
         WriteStorage<'s, Motion2DComponent>,
         WriteStorage<'s, Transform>,
         WriteStorage<'s, Hitbox2DComponent>,
     );
 
-    fn run(&mut self, (enemies, mut motion_2ds, mut transforms, mut hitbox_2ds): Self::SystemData) {
-        for (enemy, motion_2d, hitbox_2d, transform) in
-            (&enemies, &mut motion_2ds, &mut hitbox_2ds, &mut transforms).join()
+    fn run(
+        &mut self,
+        (enemies, path_followers, mut motion_2ds, mut transforms, mut hitbox_2ds): Self::SystemData,
+    ) {
+        // enemies following a formation path are moved by the PathFollowSystem
+        for (enemy, _, motion_2d, hitbox_2d, transform) in (
+            &enemies,
+            !&path_followers,
+            &mut motion_2ds,
+            &mut hitbox_2ds,
+            &mut transforms,
+        )
+            .join()
         {
             move_enemy(&enemy, transform, motion_2d, hitbox_2d);
         }
     }
 }
 
+// steer entities along their formation path
+pub struct PathFollowSystem;
+
+impl<'s> System<'s> for PathFollowSystem {
+    type SystemData = (
+        Entities<'s>,
+        WriteStorage<'s, PathFollowerComponent>,
+        WriteStorage<'s, Motion2DComponent>,
+        ReadStorage<'s, Transform>,
+        Read<'s, Time>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, mut path_followers, mut motion_2ds, transforms, time): Self::SystemData,
+    ) {
+        let dt = time.delta_seconds();
+        let mut finished_paths = vec![];
+
+        for (entity, path_follower, motion_2d, transform) in
+            (&entities, &mut path_followers, &mut motion_2ds, &transforms).join()
+        {
+            // hold position until the staggered start
+            if path_follower.delay > 0.0 {
+                path_follower.delay -= dt;
+                motion_2d.velocity = Vector2::new(0.0, 0.0);
+                continue;
+            }
+
+            let current_position =
+                Vector2::new(transform.translation().x, transform.translation().y);
+
+            if !path_follower.advance(current_position, dt) {
+                motion_2d.target_position = None;
+                finished_paths.push(entity);
+                continue;
+            }
+
+            motion_2d.target_position = path_follower.target();
+            motion_2d.move_towards_target(current_position, path_follower.speed);
+        }
+
+        // entities return to their own movement once their path is finished
+        for entity in finished_paths {
+            path_followers.remove(entity);
+        }
+    }
+}
+
 // acquire target for targeting enemies
 pub struct EnemyTargetSystem;
 
@@ -0,0 +1,95 @@
+use crate::{
+    components::{
+        ConsumableComponent, ItemComponent, PlayerComponent, PowerUpType, PowerUpsComponent,
+    },
+    constants::{PICKUP_MAGNET_SPEED, POWER_UP_MAGNET_RADIUS},
+    events::ItemGetEvent,
+};
+use amethyst::{
+    core::{math::Vector2, timing::Time, transform::Transform},
+    ecs::*,
+    shrev::{EventChannel, ReaderId},
+};
+
+// pulls consumables and items within a player's pickup radius toward the player
+#[derive(Default)]
+pub struct PickupMagnetSystem {
+    event_reader: Option<ReaderId<ItemGetEvent>>,
+}
+
+impl<'s> System<'s> for PickupMagnetSystem {
+    type SystemData = (
+        Read<'s, EventChannel<ItemGetEvent>>,
+        WriteStorage<'s, PlayerComponent>,
+        ReadStorage<'s, PowerUpsComponent>,
+        WriteStorage<'s, Transform>,
+        ReadStorage<'s, ConsumableComponent>,
+        ReadStorage<'s, ItemComponent>,
+        Read<'s, Time>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<ItemGetEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            item_get_event_channel,
+            mut players,
+            power_ups,
+            mut transforms,
+            consumables,
+            items,
+            time,
+        ): Self::SystemData,
+    ) {
+        for event in item_get_event_channel.read(self.event_reader.as_mut().unwrap()) {
+            if let Some(player) = players.get_mut(event.player_entity) {
+                if event.stat_effects.contains_key("pickup_radius") {
+                    player.pickup_radius += event.stat_effects["pickup_radius"];
+                }
+            }
+        }
+
+        let mut magnets = vec![];
+        for (player, transform, power_ups) in (&players, &transforms, power_ups.maybe()).join() {
+            // the magnet power-up extends the pickup radius while it is active
+            let radius = match power_ups {
+                Some(power_ups) if power_ups.is_active(&PowerUpType::Magnet) => {
+                    player.pickup_radius.max(POWER_UP_MAGNET_RADIUS)
+                }
+                _ => player.pickup_radius,
+            };
+
+            if radius > 0.0 {
+                magnets.push((
+                    Vector2::new(transform.translation().x, transform.translation().y),
+                    radius,
+                ));
+            }
+        }
+
+        let dt = time.delta_seconds();
+        for (magnet_position, radius) in magnets.iter() {
+            // only consumables and items are pulled
+            for (transform, _) in (&mut transforms, consumables.mask() | items.mask()).join() {
+                let pickup_position =
+                    Vector2::new(transform.translation().x, transform.translation().y);
+                let offset = magnet_position - pickup_position;
+                let distance = offset.norm();
+
+                if distance > 0.0 && distance < *radius {
+                    let step = offset / distance * (PICKUP_MAGNET_SPEED * dt).min(distance);
+                    transform.prepend_translation_x(step.x);
+                    transform.prepend_translation_y(step.y);
+                }
+            }
+        }
+    }
+}
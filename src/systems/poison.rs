@@ -0,0 +1,50 @@
+use crate::{
+    components::{DamageKind, PoisonComponent},
+    constants::POISON_TICK_PERIOD,
+    events::DamageEvent,
+};
+use amethyst::{
+    core::timing::Time,
+    ecs::prelude::{Entities, Join, Read, System, Write, WriteStorage},
+    shrev::EventChannel,
+};
+
+pub struct PoisonSystem;
+
+impl<'s> System<'s> for PoisonSystem {
+    type SystemData = (
+        Entities<'s>,
+        WriteStorage<'s, PoisonComponent>,
+        Write<'s, EventChannel<DamageEvent>>,
+        Read<'s, Time>,
+    );
+
+    fn run(&mut self, (entities, mut poisons, mut damage_event_channel, time): Self::SystemData) {
+        let mut cured_entities = vec![];
+
+        for (poisoned_entity, poison) in (&*entities, &mut poisons).join() {
+            poison.tick_timer -= time.delta_seconds();
+
+            if poison.tick_timer <= 0.0 {
+                poison.tick_timer += POISON_TICK_PERIOD;
+                poison.ticks_remaining -= 1;
+
+                damage_event_channel.single_write(DamageEvent::new(
+                    poisoned_entity,
+                    None,
+                    poison.tick_damage,
+                    DamageKind::Poison,
+                    false,
+                ));
+
+                if poison.ticks_remaining == 0 {
+                    cured_entities.push(poisoned_entity);
+                }
+            }
+        }
+
+        for entity in cured_entities {
+            poisons.remove(entity);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::components::PowerUpsComponent;
+use amethyst::{
+    core::timing::Time,
+    ecs::prelude::{Join, Read, System, WriteStorage},
+};
+
+// counts down active power-ups
+pub struct PowerUpSystem;
+
+impl<'s> System<'s> for PowerUpSystem {
+    type SystemData = (WriteStorage<'s, PowerUpsComponent>, Read<'s, Time>);
+
+    fn run(&mut self, (mut power_ups, time): Self::SystemData) {
+        for power_ups in (&mut power_ups).join() {
+            power_ups.update(time.delta_seconds());
+        }
+    }
+}
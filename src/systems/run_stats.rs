@@ -0,0 +1,117 @@
+use crate::{
+    components::{DefenseTag, EnemyComponent, PlayerComponent},
+    entities::SpawnableType,
+    events::{EnemyDestroyedEvent, EntityDamagedEvent},
+    resources::{PhaseManagerResource, RunStatsResource},
+};
+use amethyst::{
+    core::timing::Time,
+    ecs::*,
+    shrev::{EventChannel, ReaderId},
+};
+
+// records kills, damage, money and phase times for the run's stats
+#[derive(Default)]
+pub struct RunStatsSystem {
+    enemy_destroyed_event_reader: Option<ReaderId<EnemyDestroyedEvent>>,
+    entity_damaged_event_reader: Option<ReaderId<EntityDamagedEvent>>,
+    last_money: Option<usize>,
+}
+
+impl<'s> System<'s> for RunStatsSystem {
+    type SystemData = (
+        Read<'s, EventChannel<EnemyDestroyedEvent>>,
+        Read<'s, EventChannel<EntityDamagedEvent>>,
+        ReadStorage<'s, EnemyComponent>,
+        ReadStorage<'s, PlayerComponent>,
+        ReadStorage<'s, DefenseTag>,
+        Read<'s, PhaseManagerResource>,
+        Write<'s, RunStatsResource>,
+        Read<'s, Time>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.enemy_destroyed_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<EnemyDestroyedEvent>>()
+                .register_reader(),
+        );
+        self.entity_damaged_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<EntityDamagedEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            enemy_destroyed_event_channel,
+            entity_damaged_event_channel,
+            enemies,
+            players,
+            defense_tags,
+            phase_manager,
+            mut run_stats,
+            time,
+        ): Self::SystemData,
+    ) {
+        for event in
+            enemy_destroyed_event_channel.read(self.enemy_destroyed_event_reader.as_mut().unwrap())
+        {
+            if let Some(enemy) = enemies.get(event.enemy) {
+                if let SpawnableType::Enemy(enemy_type) = &enemy.spawnable_type {
+                    if !enemy.allied {
+                        *run_stats.kills.entry(enemy_type.clone()).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        for event in
+            entity_damaged_event_channel.read(self.entity_damaged_event_reader.as_mut().unwrap())
+        {
+            if let Some(enemy) = enemies.get(event.target) {
+                if !enemy.allied {
+                    *run_stats
+                        .damage_dealt
+                        .entry(event.kind.clone())
+                        .or_insert(0.0) += event.amount;
+
+                    if event.critical {
+                        run_stats.crits += 1;
+                    }
+                }
+            } else if players.get(event.target).is_some() {
+                *run_stats
+                    .damage_taken
+                    .entry(event.kind.clone())
+                    .or_insert(0.0) += event.amount;
+            } else if defense_tags.get(event.target).is_some() {
+                *run_stats
+                    .defense_damage_taken
+                    .entry(event.kind.clone())
+                    .or_insert(0.0) += event.amount;
+            }
+        }
+
+        // money changes from pickups and the store
+        if let Some(player) = (&players).join().next() {
+            if let Some(last_money) = self.last_money {
+                if player.money > last_money {
+                    run_stats.money_earned += player.money - last_money;
+                } else {
+                    run_stats.money_spent += last_money - player.money;
+                }
+            }
+            self.last_money = Some(player.money);
+        }
+
+        run_stats.add_phase_time(
+            phase_manager.phase_idx,
+            phase_manager.loop_count,
+            time.delta_seconds(),
+        );
+    }
+}
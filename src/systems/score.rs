@@ -0,0 +1,107 @@
+use crate::{
+    components::{DefenseTag, EnemyComponent, HealthComponent, PlayerComponent},
+    constants::FULL_DEFENSE_PHASE_BONUS,
+    events::{EnemyDestroyedEvent, EntityDamagedEvent, PhaseChangedEvent},
+    resources::{PhaseManagerResource, PhaseType, RunSummaryResource, ScoreResource},
+};
+use amethyst::{
+    core::timing::Time,
+    ecs::*,
+    shrev::{EventChannel, ReaderId},
+};
+
+#[derive(Default)]
+pub struct ScoreSystem {
+    enemy_destroyed_event_reader: Option<ReaderId<EnemyDestroyedEvent>>,
+    entity_damaged_event_reader: Option<ReaderId<EntityDamagedEvent>>,
+    phase_changed_event_reader: Option<ReaderId<PhaseChangedEvent>>,
+}
+
+impl<'s> System<'s> for ScoreSystem {
+    type SystemData = (
+        Read<'s, EventChannel<EnemyDestroyedEvent>>,
+        Read<'s, EventChannel<EntityDamagedEvent>>,
+        Read<'s, EventChannel<PhaseChangedEvent>>,
+        ReadStorage<'s, EnemyComponent>,
+        ReadStorage<'s, PlayerComponent>,
+        ReadStorage<'s, DefenseTag>,
+        ReadStorage<'s, HealthComponent>,
+        Read<'s, PhaseManagerResource>,
+        Write<'s, ScoreResource>,
+        Write<'s, RunSummaryResource>,
+        Read<'s, Time>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.enemy_destroyed_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<EnemyDestroyedEvent>>()
+                .register_reader(),
+        );
+        self.entity_damaged_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<EntityDamagedEvent>>()
+                .register_reader(),
+        );
+        self.phase_changed_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<PhaseChangedEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            enemy_destroyed_event_channel,
+            entity_damaged_event_channel,
+            phase_changed_event_channel,
+            enemies,
+            players,
+            defense_tags,
+            healths,
+            phase_manager,
+            mut score,
+            mut run_summary,
+            time,
+        ): Self::SystemData,
+    ) {
+        score.update(time.delta_seconds());
+
+        // taking damage ends the combo
+        for event in
+            entity_damaged_event_channel.read(self.entity_damaged_event_reader.as_mut().unwrap())
+        {
+            if players.get(event.target).is_some() {
+                score.reset_combo();
+            }
+        }
+
+        for event in
+            enemy_destroyed_event_channel.read(self.enemy_destroyed_event_reader.as_mut().unwrap())
+        {
+            if let Some(enemy) = enemies.get(event.enemy) {
+                if !enemy.allied {
+                    score.add_kill(enemy.score_value);
+                    run_summary.enemies_destroyed += 1;
+                }
+            }
+        }
+
+        // bonus for finishing a phase with the defense at full health
+        for event in
+            phase_changed_event_channel.read(self.phase_changed_event_reader.as_mut().unwrap())
+        {
+            if let PhaseType::Rest = phase_manager.phase_map[event.previous_phase].phase_type {
+                continue;
+            }
+
+            for (_defense_tag, health) in (&defense_tags, &healths).join() {
+                if health.value >= health.max_value {
+                    score.score += FULL_DEFENSE_PHASE_BONUS;
+                }
+            }
+        }
+    }
+}
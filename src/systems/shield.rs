@@ -0,0 +1,20 @@
+use crate::components::HealthComponent;
+use amethyst::{
+    core::timing::Time,
+    ecs::prelude::{Join, Read, System, WriteStorage},
+};
+
+// regenerates the shield layer of every entity with health
+pub struct ShieldSystem;
+
+impl<'s> System<'s> for ShieldSystem {
+    type SystemData = (WriteStorage<'s, HealthComponent>, Read<'s, Time>);
+
+    fn run(&mut self, (mut healths, time): Self::SystemData) {
+        for health in (&mut healths).join() {
+            if let Some(shield) = health.shield.as_mut() {
+                shield.regenerate(time.delta_seconds());
+            }
+        }
+    }
+}
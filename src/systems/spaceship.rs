@@ -2,13 +2,14 @@ use crate::{
     audio::Sounds,
     components::{
         BarrelRollAbilityComponent, BlasterComponent, HealthComponent, ManualFireComponent,
-        Motion2DComponent,
+        Motion2DComponent, PowerUpsComponent, SecondaryFireComponent, Shield,
     },
+    constants::{SPACESHIP_SHIELD_REGEN_DELAY, SPACESHIP_SHIELD_REGEN_RATE},
     events::{ItemGetEvent, PlayAudioEvent},
-    resources::SpriteSheetsResource,
+    resources::{RunStatsResource, SpriteSheetsResource},
 };
 use amethyst::{
-    core::Transform,
+    core::{timing::Time, Transform},
     ecs::*,
     ecs::{Entities, Join, LazyUpdate, Read, ReadExpect, System, Write, WriteStorage},
     input::{InputHandler, StringBindings},
@@ -29,12 +30,16 @@ impl<'s> System<'s> for SpaceshipSystem {
         WriteStorage<'s, Motion2DComponent>,
         WriteStorage<'s, BlasterComponent>,
         WriteStorage<'s, ManualFireComponent>,
+        WriteStorage<'s, SecondaryFireComponent>,
+        ReadStorage<'s, PowerUpsComponent>,
         Read<'s, InputHandler<StringBindings>>,
+        Read<'s, Time>,
         ReadExpect<'s, SpriteSheetsResource>,
         ReadExpect<'s, LazyUpdate>,
         Read<'s, EventChannel<ItemGetEvent>>,
         Write<'s, EventChannel<PlayAudioEvent>>,
         ReadExpect<'s, Sounds>,
+        Write<'s, RunStatsResource>,
     );
 
     fn setup(&mut self, world: &mut World) {
@@ -56,35 +61,44 @@ impl<'s> System<'s> for SpaceshipSystem {
             mut motion2ds,
             mut blasters,
             mut manual_fires,
+            mut secondary_fires,
+            power_ups,
             input,
+            time,
             sprite_resource,
             lazy_update,
             item_get_event_channel,
             mut play_audio_channel,
             sounds,
+            mut run_stats,
         ): Self::SystemData,
     ) {
         // collect input bools
         let shoot_action = input.action_is_down("shoot").unwrap();
+        // secondary fire may not be bound in configs generated by older versions
+        let secondary_shoot_action = input.action_is_down("secondary_shoot").unwrap_or(false);
 
-        for (health, transform, motion2d, blaster, manual_fire) in (
+        for (health, transform, motion2d, blaster, manual_fire, power_ups) in (
             &mut healths,
             &mut transforms,
             &mut motion2ds,
             &blasters,
             &mut manual_fires,
+            power_ups.maybe(),
         )
             .join()
         {
-            if shoot_action && manual_fire.ready {
-                blaster.fire(
+            if let Some(multiplier) = manual_fire.trigger(shoot_action, time.delta_seconds()) {
+                let mut fired_blaster = blaster.charged(multiplier);
+                fired_blaster.damage *= power_ups.map_or(1.0, |p| p.damage_multiplier());
+                fired_blaster.fire(
                     motion2d,
                     transform,
                     &entities,
                     &sprite_resource,
                     &lazy_update,
                 );
-                manual_fire.ready = false;
+                run_stats.blasts_fired += blaster.count;
                 play_audio_channel.single_write(PlayAudioEvent {
                     source: sounds.sound_effects["laser_blast"].clone(),
                 });
@@ -93,6 +107,34 @@ impl<'s> System<'s> for SpaceshipSystem {
             health.constrain();
         }
 
+        for (transform, motion2d, secondary_fire, power_ups) in (
+            &transforms,
+            &motion2ds,
+            &mut secondary_fires,
+            power_ups.maybe(),
+        )
+            .join()
+        {
+            if let Some(multiplier) = secondary_fire
+                .manual_fire
+                .trigger(secondary_shoot_action, time.delta_seconds())
+            {
+                let mut fired_blaster = secondary_fire.blaster.charged(multiplier);
+                fired_blaster.damage *= power_ups.map_or(1.0, |p| p.damage_multiplier());
+                fired_blaster.fire(
+                    motion2d,
+                    transform,
+                    &entities,
+                    &sprite_resource,
+                    &lazy_update,
+                );
+                run_stats.blasts_fired += secondary_fire.blaster.count;
+                play_audio_channel.single_write(PlayAudioEvent {
+                    source: sounds.sound_effects["laser_blast"].clone(),
+                });
+            }
+        }
+
         for event in item_get_event_channel.read(self.item_get_event_reader.as_mut().unwrap()) {
             let barrel_roll_ability = barrel_roll_abilities.get_mut(event.player_entity).unwrap();
             let spaceship_health = healths.get_mut(event.player_entity).unwrap();
@@ -128,6 +170,10 @@ impl<'s> System<'s> for SpaceshipSystem {
                 blaster.poison_chance += event.stat_effects["poison_chance"];
             }
 
+            if event.stat_effects.contains_key("poison_damage") {
+                blaster.poison_damage += event.stat_effects["poison_damage"];
+            }
+
             if event.stat_effects.contains_key("barrel_cooldown") {
                 barrel_roll_ability.execute_cooldown += event.stat_effects["barrel_cooldown"];
             }
@@ -152,9 +198,53 @@ impl<'s> System<'s> for SpaceshipSystem {
                 spaceship_health.value = spaceship_health.max_value;
             }
 
+            if event.stat_effects.contains_key("shield_capacity") {
+                let capacity = event.stat_effects["shield_capacity"];
+                match spaceship_health.shield.as_mut() {
+                    Some(shield) => {
+                        shield.max_value += capacity;
+                        shield.value = shield.max_value;
+                    }
+                    None => {
+                        spaceship_health.shield = Some(Shield::new(
+                            capacity,
+                            SPACESHIP_SHIELD_REGEN_RATE,
+                            SPACESHIP_SHIELD_REGEN_DELAY,
+                        ));
+                    }
+                }
+            }
+
+            if let Some(shield) = spaceship_health.shield.as_mut() {
+                if event.stat_effects.contains_key("shield_recharge_rate") {
+                    shield.regen_rate *= event.stat_effects["shield_recharge_rate"];
+                }
+
+                if event.stat_effects.contains_key("shield_recharge_delay") {
+                    shield.regen_delay =
+                        (shield.regen_delay + event.stat_effects["shield_recharge_delay"]).max(0.0);
+                }
+            }
+
             if event.stat_effects.contains_key("blast_size") {
                 blaster.size_multiplier += event.stat_effects["blast_size"];
             }
+
+            if event.stat_effects.contains_key("blast_pierce") {
+                blaster.pierce += event.stat_effects["blast_pierce"] as usize;
+            }
+
+            if event.stat_effects.contains_key("blast_bounce") {
+                blaster.bounce += event.stat_effects["blast_bounce"] as usize;
+            }
+
+            if event.stat_effects.contains_key("blast_homing") {
+                blaster.homing += event.stat_effects["blast_homing"];
+            }
+
+            if event.stat_effects.contains_key("blast_split") {
+                blaster.split += event.stat_effects["blast_split"] as usize;
+            }
         }
     }
 }
@@ -1,12 +1,12 @@
 use crate::{
     audio::Sounds,
     components::{
-        AbilityDirection, BarrelRollAbilityComponent, BarrierComponent, BlastComponent, BlastType,
-        ConsumableComponent, DefenseTag, EnemyComponent, HealthComponent, ItemComponent,
-        Motion2DComponent, PlayerComponent,
+        BarrelRollAbilityComponent, BarrierComponent, BlastComponent, BlastType,
+        ConsumableComponent, DamageKind, DefenseTag, EnemyComponent, HealthComponent,
+        InventoryComponent, ItemComponent, Motion2DComponent, PlayerComponent, PowerUpsComponent,
     },
-    entities::{spawn_effect, EffectType},
-    events::{ItemGetEvent, PlayAudioEvent, PlayerCollisionEvent},
+    entities::{spawn_effect, EffectType, SpawnableType},
+    events::{DamageEvent, ItemGetEvent, PlayAudioEvent, PlayerCollisionEvent},
     resources::{EffectsResource, GameParametersResource, SpriteSheetsResource},
     systems::{barrier_collision, immovable_collision, standard_collision},
 };
@@ -27,8 +27,7 @@ impl<'s> System<'s> for SpaceshipEnemyCollisionSystem {
         Read<'s, GameParametersResource>,
         ReadStorage<'s, EnemyComponent>,
         WriteStorage<'s, Motion2DComponent>,
-        WriteStorage<'s, HealthComponent>,
-        ReadStorage<'s, BarrelRollAbilityComponent>,
+        Write<'s, EventChannel<DamageEvent>>,
     );
 
     fn setup(&mut self, world: &mut World) {
@@ -47,31 +46,21 @@ impl<'s> System<'s> for SpaceshipEnemyCollisionSystem {
             game_parameters,
             enemies,
             mut motions,
-            mut healths,
-            barrel_roll_abilities,
+            mut damage_event_channel,
         ): Self::SystemData,
     ) {
         for event in collision_event_channel.read(self.event_reader.as_mut().unwrap()) {
             // Is the player colliding with an enemy entity?
             if let Some(enemy) = enemies.get(event.colliding_entity) {
                 let spaceship_motion = motions.get_mut(event.player_entity).unwrap();
-                let spaceship_health = healths.get_mut(event.player_entity).unwrap();
-
-                let collision_damage_immune = if let Some(barrel_roll_ability) =
-                    barrel_roll_abilities.get(event.player_entity)
-                {
-                    if let AbilityDirection::None = barrel_roll_ability.action_direction {
-                        false
-                    } else {
-                        barrel_roll_ability.steel_barrel
-                    }
-                } else {
-                    false
-                };
 
-                if !collision_damage_immune {
-                    spaceship_health.take_damage(enemy.collision_damage);
-                }
+                damage_event_channel.single_write(DamageEvent::new(
+                    event.player_entity,
+                    Some(event.colliding_entity),
+                    enemy.collision_damage,
+                    DamageKind::Collision,
+                    false,
+                ));
 
                 if let Some(collision_velocity) = event.collision_velocity {
                     if event.collider_immovable {
@@ -102,13 +91,13 @@ impl<'s> System<'s> for SpaceshipBlastCollisionSystem {
     type SystemData = (
         Read<'s, EventChannel<PlayerCollisionEvent>>,
         Entities<'s>,
-        WriteStorage<'s, HealthComponent>,
         WriteStorage<'s, BlastComponent>,
         ReadStorage<'s, BarrelRollAbilityComponent>,
         ReadStorage<'s, Transform>,
         ReadExpect<'s, EffectsResource>,
         ReadExpect<'s, SpriteSheetsResource>,
         ReadExpect<'s, LazyUpdate>,
+        Write<'s, EventChannel<DamageEvent>>,
     );
 
     fn setup(&mut self, world: &mut World) {
@@ -125,35 +114,30 @@ impl<'s> System<'s> for SpaceshipBlastCollisionSystem {
         (
             collision_event_channel,
             entities,
-            mut healths,
             mut blasts,
             barrel_roll_abilities,
             transforms,
             effects_resource,
             sprite_resource,
             lazy_update,
+            mut damage_event_channel,
         ): Self::SystemData,
     ) {
         for event in collision_event_channel.read(self.event_reader.as_mut().unwrap()) {
             // Is the player colliding with an entity with a blast component?
             if let Some(blast) = blasts.get_mut(event.colliding_entity) {
-                let spaceship_health = healths.get_mut(event.player_entity).unwrap();
                 let blast_transform = transforms.get(event.colliding_entity).unwrap();
 
+                // blasts pass through the player while barrel rolling
                 let player_hittable = if let Some(barrel_roll_ability) =
                     barrel_roll_abilities.get(event.player_entity)
                 {
-                    if let AbilityDirection::None = barrel_roll_ability.action_direction {
-                        true
-                    } else {
-                        false
-                    }
+                    !barrel_roll_ability.is_invulnerable_to(&DamageKind::Blast)
                 } else {
                     true
                 };
 
                 // first check if the blast is allied with the player
-                // TODO blast should not hit if player is currently barrel rolling
                 if player_hittable {
                     match blast.blast_type {
                         // using match here for ease of adding enemy blast effects (such as poison) in the future
@@ -170,7 +154,13 @@ impl<'s> System<'s> for SpaceshipBlastCollisionSystem {
                                 &entities,
                                 &lazy_update,
                             );
-                            spaceship_health.take_damage(blast.damage);
+                            damage_event_channel.single_write(DamageEvent::new(
+                                event.player_entity,
+                                Some(event.colliding_entity),
+                                blast.damage,
+                                DamageKind::Blast,
+                                false,
+                            ));
                         }
                         _ => {}
                     }
@@ -190,6 +180,7 @@ impl<'s> System<'s> for SpaceshipItemCollisionSystem {
         Read<'s, EventChannel<PlayerCollisionEvent>>,
         Entities<'s>,
         ReadStorage<'s, ItemComponent>,
+        WriteStorage<'s, InventoryComponent>,
         Write<'s, EventChannel<ItemGetEvent>>,
         Write<'s, EventChannel<PlayAudioEvent>>,
         ReadExpect<'s, Sounds>,
@@ -210,6 +201,7 @@ impl<'s> System<'s> for SpaceshipItemCollisionSystem {
             collision_event_channel,
             entities,
             items,
+            mut inventories,
             mut item_get_event_channel,
             mut play_audio_channel,
             sounds,
@@ -218,10 +210,22 @@ impl<'s> System<'s> for SpaceshipItemCollisionSystem {
         for event in collision_event_channel.read(self.event_reader.as_mut().unwrap()) {
             // Is the player colliding with an entity with an item component?
             if let Some(item) = items.get(event.colliding_entity) {
+                let item_type = match &item.spawnable_type {
+                    SpawnableType::Item(item_type) => Some(item_type.clone()),
+                    _ => None,
+                };
+
+                if let (Some(inventory), Some(item_type)) =
+                    (inventories.get_mut(event.player_entity), &item_type)
+                {
+                    inventory.add(item_type);
+                }
+
                 item_get_event_channel.single_write(ItemGetEvent::new(
                     event.player_entity,
                     item.stat_effects.clone(),
                     item.bool_effects.clone(),
+                    item_type,
                 ));
 
                 play_audio_channel.single_write(PlayAudioEvent {
@@ -249,6 +253,7 @@ impl<'s> System<'s> for SpaceshipConsumableCollisionSystem {
         WriteStorage<'s, PlayerComponent>,
         ReadStorage<'s, DefenseTag>,
         WriteStorage<'s, HealthComponent>,
+        WriteStorage<'s, PowerUpsComponent>,
         Write<'s, EventChannel<PlayAudioEvent>>,
         ReadExpect<'s, Sounds>,
     );
@@ -271,6 +276,7 @@ impl<'s> System<'s> for SpaceshipConsumableCollisionSystem {
             mut players,
             defense_tags,
             mut healths,
+            mut power_ups,
             mut play_audio_channel,
             sounds,
         ): Self::SystemData,
@@ -284,6 +290,12 @@ impl<'s> System<'s> for SpaceshipConsumableCollisionSystem {
                 spaceship_health.value += consumable.health_value;
                 spaceship_health.armor += consumable.armor_value;
                 player.money += consumable.money_value;
+                player.add_bombs(consumable.bomb_value);
+                if let (Some(power_up), Some(player_power_ups)) =
+                    (&consumable.power_up, power_ups.get_mut(event.player_entity))
+                {
+                    player_power_ups.activate(power_up);
+                }
                 for (_defense_tag, defense_health) in (&defense_tags, &mut healths).join() {
                     defense_health.value += consumable.defense_value;
                 }
@@ -310,7 +322,7 @@ impl<'s> System<'s> for SpaceshipArenaBorderCollisionSystem {
         Read<'s, EventChannel<PlayerCollisionEvent>>,
         ReadStorage<'s, BarrierComponent>,
         WriteStorage<'s, Motion2DComponent>,
-        WriteStorage<'s, HealthComponent>,
+        Write<'s, EventChannel<DamageEvent>>,
         Write<'s, EventChannel<PlayAudioEvent>>,
         ReadExpect<'s, Sounds>,
     );
@@ -330,7 +342,7 @@ impl<'s> System<'s> for SpaceshipArenaBorderCollisionSystem {
             collision_event_channel,
             barriers,
             mut motion_2ds,
-            mut healths,
+            mut damage_event_channel,
             mut play_audio_channel,
             sounds,
         ): Self::SystemData,
@@ -339,11 +351,16 @@ impl<'s> System<'s> for SpaceshipArenaBorderCollisionSystem {
             // Is the player colliding with a barrier?
             if let Some(barrier) = barriers.get(event.colliding_entity) {
                 let player_motion = motion_2ds.get_mut(event.player_entity).unwrap();
-                let player_health = healths.get_mut(event.player_entity).unwrap();
 
                 barrier_collision(player_motion, barrier);
 
-                player_health.value -= barrier.damage;
+                damage_event_channel.single_write(DamageEvent::new(
+                    event.player_entity,
+                    Some(event.colliding_entity),
+                    barrier.damage,
+                    DamageKind::Barrier,
+                    false,
+                ));
 
                 play_audio_channel.single_write(PlayAudioEvent {
                     source: sounds.sound_effects["force_field"].clone(),
@@ -1,9 +1,11 @@
 use crate::{
     components::{EnemySpawnerTag, SpawnerComponent},
-    entities::{spawn_enemy, spawn_repeater, SpawnableType},
+    constants::ENEMY_SPAWN_INTERVAL,
+    entities::{default_enemy_spawn_probabilities, spawn_enemy, spawn_repeater, SpawnableType},
     resources::{
         BossType, ConsumablesResource, EffectsResource, EnemiesResource, FormationsResource,
-        ItemsResource, PhaseManagerResource, PhaseType, SpriteSheetsResource,
+        GameParametersResource, ItemsResource, PhaseManagerResource, PhaseType,
+        SpriteSheetsResource,
     },
 };
 use amethyst::{
@@ -14,7 +16,10 @@ use amethyst::{
     },
 };
 
-pub struct SpawnerSystem;
+#[derive(Default)]
+pub struct SpawnerSystem {
+    active_phase: Option<usize>,
+}
 
 impl<'s> System<'s> for SpawnerSystem {
     type SystemData = (
@@ -31,6 +36,7 @@ impl<'s> System<'s> for SpawnerSystem {
         ReadExpect<'s, ItemsResource>,
         ReadExpect<'s, EffectsResource>,
         ReadExpect<'s, EnemiesResource>,
+        Read<'s, GameParametersResource>,
     );
 
     fn run(
@@ -49,14 +55,42 @@ impl<'s> System<'s> for SpawnerSystem {
             items_resource,
             effects_resource,
             enemies_resource,
+            game_parameters,
         ): Self::SystemData,
     ) {
+        let spawn_rate = game_parameters
+            .difficulty(phase_manager.loop_count)
+            .spawn_rate;
+
         if phase_manager.phase_idx < phase_manager.last_phase {
             match phase_manager.phase_map[phase_manager.phase_idx].phase_type {
                 PhaseType::RandomInvasion => {
+                    let phase_idx = phase_manager.phase_idx;
+                    let phase_progress = phase_manager.phase_progress();
+                    let spawn_table = &phase_manager.phase_map[phase_idx].spawn_table;
+
+                    let phase_changed = self.active_phase != Some(phase_idx);
+                    self.active_phase = Some(phase_idx);
+
                     for (spawner, transform, _) in
                         (&mut spawners, &mut transforms, &spawner_tag).join()
                     {
+                        // reconfigure the spawner when a new phase begins, then ramp its period
+                        if phase_changed {
+                            match spawn_table {
+                                Some(spawn_table) => spawner.reconfigure(
+                                    spawn_table.probabilities.clone(),
+                                    spawn_table.period_at(phase_progress) / spawn_rate,
+                                ),
+                                None => spawner.reconfigure(
+                                    default_enemy_spawn_probabilities(),
+                                    ENEMY_SPAWN_INTERVAL / spawn_rate,
+                                ),
+                            }
+                        } else if let Some(spawn_table) = spawn_table {
+                            spawner.set_period(spawn_table.period_at(phase_progress) / spawn_rate);
+                        }
+
                         if let Some((new_x, Some(enemy_type))) =
                             spawner.spawn_with_position(time.delta_seconds())
                         {
@@ -82,7 +116,10 @@ impl<'s> System<'s> for SpawnerSystem {
                 }
 
                 PhaseType::FormationInvasion => formations_resource
-                    .spawn_random_formation_when_ready(
+                    .spawn_phase_formation_when_ready(
+                        phase_manager.phase_idx,
+                        &phase_manager.phase_map[phase_manager.phase_idx].formations,
+                        spawn_rate,
                         time.delta_seconds(),
                         &consumables_resource,
                         &enemies_resource,
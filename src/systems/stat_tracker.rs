@@ -1,11 +1,15 @@
 use crate::{
-    components::{HealthComponent, PlayerComponent},
+    components::{HealthComponent, PlayerComponent, PowerUpsComponent},
+    constants::STORE_LOCKED_PRICE_COLOR,
     entities::SpawnableType,
-    resources::{ConsumablesResource, ItemsResource, StoreResource},
+    resources::{
+        ConsumablesResource, ItemsResource, PhaseManagerResource, RunSummaryResource,
+        ScoreResource, StoreResource,
+    },
     states::TrackedStats,
 };
 use amethyst::{
-    ecs::prelude::{Join, ReadExpect, ReadStorage, System, WriteStorage},
+    ecs::prelude::{Join, Read, ReadExpect, ReadStorage, System, WriteStorage},
     ui::UiText,
 };
 
@@ -15,11 +19,15 @@ impl<'s> System<'s> for StatTrackerSystem {
     type SystemData = (
         ReadStorage<'s, PlayerComponent>,
         ReadStorage<'s, HealthComponent>,
+        ReadStorage<'s, PowerUpsComponent>,
         ReadExpect<'s, StoreResource>,
         WriteStorage<'s, UiText>,
         ReadExpect<'s, TrackedStats>,
         ReadExpect<'s, ItemsResource>,
         ReadExpect<'s, ConsumablesResource>,
+        Read<'s, PhaseManagerResource>,
+        Read<'s, RunSummaryResource>,
+        Read<'s, ScoreResource>,
     );
 
     fn run(
@@ -27,17 +35,25 @@ impl<'s> System<'s> for StatTrackerSystem {
         (
             players,
             healths,
+            power_ups,
             store_resource,
             mut ui_text,
             tracked_stats,
             items_resource,
             consumables_resource,
+            phase_manager,
+            run_summary,
+            score,
         ): Self::SystemData,
     ) {
         for character in (&players).join() {
             if let Some(text) = ui_text.get_mut(tracked_stats.currency) {
                 text.text = format!("x{}", character.money.to_string());
             }
+
+            if let Some(text) = ui_text.get_mut(tracked_stats.bombs) {
+                text.text = format!("x{}", character.bombs.to_string());
+            }
         }
 
         for (_player, health) in (&players, &healths).join() {
@@ -46,57 +62,93 @@ impl<'s> System<'s> for StatTrackerSystem {
             }
         }
 
-        if let Some(text) = ui_text.get_mut(tracked_stats.item_price_1) {
-            text.text = format!(
-                "${}",
-                inventory_price(
-                    &store_resource.inventory[0],
-                    &items_resource,
-                    &consumables_resource
-                )
-            );
+        // the loop is only shown in endless mode
+        if let Some(text) = ui_text.get_mut(tracked_stats.loop_count) {
+            text.text = if run_summary.endless_mode {
+                format!("loop {}", phase_manager.loop_count + 1)
+            } else {
+                "".to_string()
+            };
         }
 
-        if let Some(text) = ui_text.get_mut(tracked_stats.item_price_2) {
-            text.text = format!(
-                "${}",
-                inventory_price(
-                    &store_resource.inventory[1],
-                    &items_resource,
-                    &consumables_resource
-                )
-            );
+        if let Some(text) = ui_text.get_mut(tracked_stats.score) {
+            text.text = score.score.to_string();
+        }
+
+        // the combo multiplier is only shown once a combo has started
+        if let Some(text) = ui_text.get_mut(tracked_stats.combo) {
+            text.text = if score.combo > 1 {
+                format!("x{:.1}", score.multiplier())
+            } else {
+                "".to_string()
+            };
         }
 
-        if let Some(text) = ui_text.get_mut(tracked_stats.item_price_3) {
+        // one line per active power-up with its remaining seconds
+        for (_player, power_ups) in (&players, &power_ups).join() {
+            if let Some(text) = ui_text.get_mut(tracked_stats.power_ups) {
+                text.text = power_ups
+                    .active
+                    .iter()
+                    .map(|(power_up_type, timer)| {
+                        format!("{} {:.1}", power_up_type.display_name(), timer)
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+            }
+        }
+
+        if let Some(text) = ui_text.get_mut(tracked_stats.reroll_cost) {
             text.text = format!(
-                "${}",
-                inventory_price(
-                    &store_resource.inventory[2],
-                    &items_resource,
-                    &consumables_resource
-                )
+                "reroll ${}",
+                store_resource.price(store_resource.reroll_cost)
             );
         }
+
+        // prices of locked slots are tinted
+        for (inventory_index, item_price) in tracked_stats.item_prices.iter().enumerate() {
+            if let Some(text) = ui_text.get_mut(*item_price) {
+                text.text = format!(
+                    "${}",
+                    inventory_price(
+                        inventory_index,
+                        &store_resource,
+                        &items_resource,
+                        &consumables_resource
+                    )
+                );
+                text.color = if store_resource.is_locked(inventory_index) {
+                    STORE_LOCKED_PRICE_COLOR
+                } else {
+                    [1.0, 1.0, 1.0, 1.0]
+                };
+            }
+        }
     }
 }
 
 fn inventory_price(
-    inventory_entity: &Option<SpawnableType>,
+    inventory_index: usize,
+    store_resource: &ReadExpect<StoreResource>,
     items_resource: &ReadExpect<ItemsResource>,
     consumables_resource: &ReadExpect<ConsumablesResource>,
 ) -> usize {
-    match inventory_entity {
-        Some(SpawnableType::Item(item_type)) => {
+    match store_resource
+        .inventory
+        .get(inventory_index)
+        .cloned()
+        .flatten()
+    {
+        Some(SpawnableType::Item(item_type)) => store_resource.price(
             items_resource.item_entities[&item_type]
                 .item_component
-                .price
-        }
-        Some(SpawnableType::Consumable(consumable_type)) => {
+                .price,
+        ),
+        Some(SpawnableType::Consumable(consumable_type)) => store_resource.price(
             consumables_resource.consumable_entities[&consumable_type]
                 .consumable_component
-                .price
-        }
+                .price,
+        ),
         _ => 0,
     }
 }
@@ -14,6 +14,7 @@ const HEALTH_SPRITE_INDEX: usize = 0;
 const DEFENSE_SPRITE_INDEX: usize = 1;
 const ROLL_SPRITE_INDEX: usize = 2;
 const RESTOCK_SPRITE_INDEX: usize = 3;
+const SHIELD_SPRITE_INDEX: usize = 4;
 
 pub struct StatusBarSystem;
 
@@ -62,6 +63,24 @@ impl<'s> System<'s> for StatusBarSystem {
                     }
                 }
 
+                StatusType::Shield => {
+                    for (_player, health) in (&players, &healths).join() {
+                        if let Some(shield) = &health.shield {
+                            if let Some(status_position) =
+                                status_bar.update_units_y(shield.max_value, shield.value, &entities)
+                            {
+                                status_bar.status_unit_stack.push(spawn_status_unit(
+                                    &entities,
+                                    &sprite_resource,
+                                    SHIELD_SPRITE_INDEX,
+                                    status_position,
+                                    &lazy_update,
+                                ));
+                            }
+                        }
+                    }
+                }
+
                 StatusType::Defense => {
                     for (_defense_tag, defense_health) in (&defense_tags, &healths).join() {
                         if let Some(status_position) = status_bar.update_units_y(
@@ -3,7 +3,10 @@ use crate::{
     components::{PlayerComponent, StoreIconComponent},
     entities::SpawnableType,
     events::PlayAudioEvent,
-    resources::{ConsumablesResource, ItemsResource, SpriteSheetsResource, StoreResource},
+    resources::{
+        ConsumablesResource, ItemsResource, PhaseManagerResource, PurchaseRecord, RunStatsResource,
+        RunSummaryResource, SpriteSheetsResource, StoreResource,
+    },
 };
 use amethyst::{
     core::{timing::Time, Transform},
@@ -16,7 +19,11 @@ use amethyst::{
     shrev::EventChannel,
 };
 
-pub struct StoreSystem;
+#[derive(Default)]
+pub struct StoreSystem {
+    reroll_action_held: bool,
+    buy_actions_held: Vec<bool>,
+}
 
 impl<'s> System<'s> for StoreSystem {
     type SystemData = (
@@ -34,6 +41,9 @@ impl<'s> System<'s> for StoreSystem {
         ReadExpect<'s, Sounds>,
         ReadStorage<'s, StoreIconComponent>,
         WriteStorage<'s, SpriteRender>,
+        Write<'s, RunSummaryResource>,
+        Write<'s, RunStatsResource>,
+        Read<'s, PhaseManagerResource>,
     );
 
     fn run(
@@ -53,37 +63,113 @@ impl<'s> System<'s> for StoreSystem {
             sounds,
             store_icons,
             mut sprite_renders,
+            mut run_summary,
+            mut run_stats,
+            phase_manager,
         ): Self::SystemData,
     ) {
-        let buy_0_action = input.action_is_down("buy_0").unwrap();
-        let buy_1_action = input.action_is_down("buy_1").unwrap();
-        let buy_2_action = input.action_is_down("buy_2").unwrap();
+        // actions may not be bound in configs generated by older versions
+        let slot_count = store_resource.slot_count;
+        let buy_actions: Vec<bool> = (0..slot_count)
+            .map(|i| input.action_is_down(&format!("buy_{}", i)).unwrap_or(false))
+            .collect();
+        let lock_action = input.action_is_down("lock_slot").unwrap_or(false);
+        let reroll_action = input.action_is_down("reroll").unwrap_or(false);
+
+        // rerolls and locks only happen once per press
+        self.buy_actions_held.resize(slot_count, false);
+        let buy_pressed: Vec<bool> = buy_actions
+            .iter()
+            .zip(self.buy_actions_held.iter())
+            .map(|(action, held)| *action && !held)
+            .collect();
+        let reroll_pressed = reroll_action && !self.reroll_action_held;
+        self.buy_actions_held = buy_actions.clone();
+        self.reroll_action_held = reroll_action;
+
+        store_resource.restock_when_ready(time.delta_seconds());
+
+        // TODO: streamline purchase_item function with constant component in item data file
+        for (character, transform) in (&mut players, &transforms).join() {
+            if lock_action {
+                for (inventory_index, pressed) in buy_pressed.iter().enumerate() {
+                    if *pressed {
+                        store_resource.toggle_lock(inventory_index);
+                    }
+                }
+                continue;
+            }
+
+            if reroll_pressed && store_resource.reroll(character) {
+                play_audio_channel.single_write(PlayAudioEvent {
+                    source: sounds.sound_effects["cash_register_bell"].clone(),
+                });
+            }
+
+            for (inventory_index, action) in buy_actions.iter().enumerate() {
+                if !action {
+                    continue;
+                }
+
+                let purchased_type = store_resource.inventory[inventory_index].clone();
+
+                if store_resource.purchase(
+                    inventory_index,
+                    &entities,
+                    character,
+                    transform,
+                    &items_resource,
+                    &consumables_resource,
+                    &spritesheets_resource,
+                    &lazy_update,
+                ) {
+                    play_audio_channel.single_write(PlayAudioEvent {
+                        source: sounds.sound_effects["cash_register_bell"].clone(),
+                    });
+
+                    if let Some(spawnable_type) = purchased_type {
+                        if let SpawnableType::Item(_) = spawnable_type {
+                            run_summary.items_purchased += 1;
+                        }
+
+                        run_stats.purchases.push(PurchaseRecord {
+                            spawnable_type,
+                            phase_idx: phase_manager.phase_idx,
+                            loop_count: phase_manager.loop_count,
+                        });
+                    }
+                }
+            }
+        }
 
-        if store_resource.restock_when_ready(time.delta_seconds()) {
-            // change store icons
-            for (store_icon, sprite_render) in (&store_icons, &mut sprite_renders).join() {
-                if let Some(SpawnableType::Item(item_type)) =
-                    store_resource.inventory[store_icon.inventory_index].clone()
-                {
-                    let item_data = items_resource.item_entities[&item_type].clone();
+        // store icons show the stock of their slot
+        for (store_icon, sprite_render) in (&store_icons, &mut sprite_renders).join() {
+            match store_resource
+                .inventory
+                .get(store_icon.inventory_index)
+                .cloned()
+                .flatten()
+            {
+                Some(SpawnableType::Item(item_type)) => {
+                    let item_data = &items_resource.item_entities[&item_type];
                     *sprite_render = SpriteRender {
                         sprite_sheet: spritesheets_resource.spritesheets
                             [&item_data.sprite_render_data.spritesheet]
                             .clone(),
                         sprite_number: item_data.sprite_render_data.initial_index,
                     }
-                } else if let Some(SpawnableType::Consumable(consumable_type)) =
-                    store_resource.inventory[store_icon.inventory_index].clone()
-                {
+                }
+                Some(SpawnableType::Consumable(consumable_type)) => {
                     let consumable_data =
-                        consumables_resource.consumable_entities[&consumable_type].clone();
+                        &consumables_resource.consumable_entities[&consumable_type];
                     *sprite_render = SpriteRender {
                         sprite_sheet: spritesheets_resource.spritesheets
                             [&consumable_data.sprite_render_data.spritesheet]
                             .clone(),
                         sprite_number: consumable_data.sprite_render_data.initial_index,
                     }
-                } else {
+                }
+                _ => {
                     // if no inventory in slot put blank sprite
                     *sprite_render = SpriteRender {
                         sprite_sheet: spritesheets_resource.spritesheets["items"].clone(),
@@ -92,69 +178,5 @@ impl<'s> System<'s> for StoreSystem {
                 }
             }
         }
-
-        // TODO: streamline purchase_item function with constant component in item data file
-        for (character, transform) in (&mut players, &transforms).join() {
-            if (buy_0_action
-                && store_resource.purchase(
-                    0,
-                    &entities,
-                    character,
-                    transform,
-                    &items_resource,
-                    &consumables_resource,
-                    &spritesheets_resource,
-                    &lazy_update,
-                ))
-                || (buy_1_action
-                    && store_resource.purchase(
-                        1,
-                        &entities,
-                        character,
-                        transform,
-                        &items_resource,
-                        &consumables_resource,
-                        &spritesheets_resource,
-                        &lazy_update,
-                    ))
-                || (buy_2_action
-                    && store_resource.purchase(
-                        2,
-                        &entities,
-                        character,
-                        transform,
-                        &items_resource,
-                        &consumables_resource,
-                        &spritesheets_resource,
-                        &lazy_update,
-                    ))
-            {
-                play_audio_channel.single_write(PlayAudioEvent {
-                    source: sounds.sound_effects["cash_register_bell"].clone(),
-                });
-
-                // change store icons
-                for (store_icon, sprite_render) in (&store_icons, &mut sprite_renders).join() {
-                    if buy_0_action && store_icon.inventory_index == 0 {
-                        *sprite_render = SpriteRender {
-                            sprite_sheet: spritesheets_resource.spritesheets["items"].clone(),
-                            sprite_number: 0,
-                        }
-                    }
-                    if buy_1_action && store_icon.inventory_index == 1 {
-                        *sprite_render = SpriteRender {
-                            sprite_sheet: spritesheets_resource.spritesheets["items"].clone(),
-                            sprite_number: 0,
-                        }
-                    }
-                    if buy_2_action && store_icon.inventory_index == 2 {
-                        *sprite_render = SpriteRender {
-                            sprite_sheet: spritesheets_resource.spritesheets["items"].clone(),
-                            sprite_number: 0,
-                        }
-                    }
-                }
-            }
-        }
     }
 }